@@ -1,7 +1,8 @@
-use egui::{pos2, Pos2, Vec2};
+use egui::{pos2, vec2, Align, Align2, Pos2, Rect, Vec2};
 
 /// Anchor where to show toasts
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Anchor {
     /// Top right corner.
     TopRight,
@@ -11,48 +12,249 @@ pub enum Anchor {
     BottomRight,
     /// Bottom left corner
     BottomLeft,
+    /// Center of the top edge.
+    TopCenter,
+    /// Center of the bottom edge.
+    BottomCenter,
+    /// Center of the left edge.
+    CenterLeft,
+    /// Center of the right edge.
+    CenterRight,
+    /// Center of the screen.
+    Center,
 }
 
 impl Anchor {
+    #[inline]
+    pub(crate) const fn align(&self) -> Align2 {
+        match self {
+            Self::TopRight => Align2::RIGHT_TOP,
+            Self::TopLeft => Align2::LEFT_TOP,
+            Self::BottomRight => Align2::RIGHT_BOTTOM,
+            Self::BottomLeft => Align2::LEFT_BOTTOM,
+            Self::TopCenter => Align2::CENTER_TOP,
+            Self::BottomCenter => Align2::CENTER_BOTTOM,
+            Self::CenterLeft => Align2::LEFT_CENTER,
+            Self::CenterRight => Align2::RIGHT_CENTER,
+            Self::Center => Align2::CENTER_CENTER,
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_align(align: Align2) -> Self {
+        match align.0 {
+            [Align::Max, Align::Min] => Self::TopRight,
+            [Align::Min, Align::Min] => Self::TopLeft,
+            [Align::Max, Align::Max] => Self::BottomRight,
+            [Align::Min, Align::Max] => Self::BottomLeft,
+            [Align::Center, Align::Min] => Self::TopCenter,
+            [Align::Center, Align::Max] => Self::BottomCenter,
+            [Align::Min, Align::Center] => Self::CenterLeft,
+            [Align::Max, Align::Center] => Self::CenterRight,
+            [Align::Center, Align::Center] => Self::Center,
+        }
+    }
+
     #[inline]
     pub(crate) const fn anim_side(&self) -> f32 {
         match self {
-            Self::TopRight | Self::BottomRight => 1.,
-            Self::TopLeft | Self::BottomLeft => -1.,
+            Self::TopRight | Self::BottomRight | Self::CenterRight => 1.,
+            Self::TopLeft | Self::BottomLeft | Self::CenterLeft => -1.,
+            Self::TopCenter | Self::BottomCenter | Self::Center => 0.,
         }
     }
+
+    /// Direction in which toasts slide out, zero if they only fade.
+    #[inline]
+    pub(crate) const fn anim_dir(&self) -> Vec2 {
+        match self {
+            Self::TopCenter => vec2(0., -1.),
+            Self::BottomCenter => vec2(0., 1.),
+            _ => vec2(self.anim_side(), 0.),
+        }
+    }
+
+    /// Whether toasts fade in and out in addition to sliding.
+    #[inline]
+    pub(crate) const fn fades(&self) -> bool {
+        matches!(self, Self::TopCenter | Self::BottomCenter | Self::Center)
+    }
 }
 
 impl Anchor {
-    pub(crate) fn screen_corner(&self, sc: Pos2, margin: Vec2) -> Pos2 {
-        let mut out = match self {
-            Self::TopRight => pos2(sc.x, 0.),
-            Self::TopLeft => pos2(0., 0.),
-            Self::BottomRight => sc,
-            Self::BottomLeft => pos2(0., sc.y),
-        };
+    pub(crate) fn screen_corner(&self, screen: Rect, margin: Vec2) -> Pos2 {
+        let mut out = self.align().pos_in_rect(&screen);
         self.apply_margin(&mut out, margin);
         out
     }
 
     pub(crate) fn apply_margin(&self, pos: &mut Pos2, margin: Vec2) {
-        match self {
-            Self::TopRight => {
-                pos.x -= margin.x;
-                pos.y += margin.y;
-            }
-            Self::TopLeft => {
-                pos.x += margin.x;
-                pos.y += margin.y;
-            }
-            Self::BottomRight => {
-                pos.x -= margin.x;
-                pos.y -= margin.y;
+        // Move away from the edges, centered axes are left as is
+        *pos -= margin * self.align().to_sign();
+    }
+
+    /// Alignment of a single toast relative to the stack position,
+    /// vertically centered stacks are laid out from top to bottom.
+    pub(crate) fn toast_align(&self) -> Align2 {
+        let align = self.align();
+        match align.y() {
+            Align::Center => Align2([align.x(), Align::Min]),
+            _ => align,
+        }
+    }
+
+    /// Places a stack of `size` outside of `rect` on the side named by `self`,
+    /// flipping it if it would leave `screen`.
+    /// Returns the stack position and the anchor to lay the stack out with.
+    pub(crate) fn attach_to(
+        &self,
+        rect: Rect,
+        size: Vec2,
+        screen: Rect,
+        margin: Vec2,
+    ) -> (Pos2, Self) {
+        let (mut x, mut y) = (self.align().x(), self.align().y());
+
+        match (x, y) {
+            (Align::Center, Align::Center) => (rect.center(), Self::Center),
+            // Beside the rect, the stack extends away from it
+            (_, Align::Center) => {
+                let fits = match x {
+                    Align::Max => rect.max.x + margin.x + size.x <= screen.max.x,
+                    _ => rect.min.x - margin.x - size.x >= screen.min.x,
+                };
+                if !fits {
+                    x = x.flip();
+                }
+
+                let pos_x = match x {
+                    Align::Max => rect.max.x + margin.x,
+                    _ => rect.min.x - margin.x,
+                };
+                let anchor = Self::from_align(Align2([x.flip(), Align::Center]));
+                (pos2(pos_x, rect.center().y), anchor)
             }
-            Self::BottomLeft => {
-                pos.x += margin.x;
-                pos.y -= margin.y;
+            // Above or below the rect, aligned with one of its edges
+            _ => {
+                let fits = match y {
+                    Align::Min => rect.min.y - margin.y - size.y >= screen.min.y,
+                    _ => rect.max.y + margin.y + size.y <= screen.max.y,
+                };
+                if !fits {
+                    y = y.flip();
+                }
+
+                let fits = match x {
+                    Align::Max => rect.max.x - size.x >= screen.min.x,
+                    Align::Min => rect.min.x + size.x <= screen.max.x,
+                    Align::Center => true,
+                };
+                if !fits {
+                    x = x.flip();
+                }
+
+                let pos_x = rect.min.x + x.to_factor() * rect.width();
+                let pos_y = match y {
+                    Align::Min => rect.min.y - margin.y,
+                    _ => rect.max.y + margin.y,
+                };
+                let anchor = Self::from_align(Align2([x, y.flip()]));
+                (pos2(pos_x, pos_y), anchor)
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCREEN: Rect = Rect::from_min_max(pos2(0., 0.), pos2(800., 600.));
+    const MARGIN: Vec2 = vec2(8., 8.);
+    const SIZE: Vec2 = vec2(180., 100.);
+
+    fn attach(anchor: Anchor, min: Pos2, max: Pos2) -> (Pos2, Anchor) {
+        anchor.attach_to(Rect::from_min_max(min, max), SIZE, SCREEN, MARGIN)
+    }
+
+    #[test]
+    fn attach_beside() {
+        assert_eq!(
+            attach(Anchor::CenterRight, pos2(100., 280.), pos2(200., 320.)),
+            (pos2(208., 300.), Anchor::CenterLeft)
+        );
+        assert_eq!(
+            attach(Anchor::CenterLeft, pos2(600., 280.), pos2(700., 320.)),
+            (pos2(592., 300.), Anchor::CenterRight)
+        );
+    }
+
+    #[test]
+    fn attach_flips_off_right_edge() {
+        assert_eq!(
+            attach(Anchor::CenterRight, pos2(700., 280.), pos2(780., 320.)),
+            (pos2(692., 300.), Anchor::CenterRight)
+        );
+        // Edge aligned stacks flip to the other edge of the rect
+        assert_eq!(
+            attach(Anchor::TopLeft, pos2(700., 300.), pos2(780., 340.)),
+            (pos2(780., 292.), Anchor::BottomRight)
+        );
+    }
+
+    #[test]
+    fn attach_flips_off_left_edge() {
+        assert_eq!(
+            attach(Anchor::CenterLeft, pos2(20., 280.), pos2(100., 320.)),
+            (pos2(108., 300.), Anchor::CenterLeft)
+        );
+        assert_eq!(
+            attach(Anchor::BottomRight, pos2(20., 300.), pos2(100., 340.)),
+            (pos2(20., 348.), Anchor::TopLeft)
+        );
+    }
+
+    #[test]
+    fn attach_flips_off_top_edge() {
+        assert_eq!(
+            attach(Anchor::TopLeft, pos2(300., 300.), pos2(400., 340.)),
+            (pos2(300., 292.), Anchor::BottomLeft)
+        );
+        assert_eq!(
+            attach(Anchor::TopLeft, pos2(100., 20.), pos2(200., 60.)),
+            (pos2(100., 68.), Anchor::TopLeft)
+        );
+    }
+
+    #[test]
+    fn attach_flips_off_bottom_edge() {
+        assert_eq!(
+            attach(Anchor::BottomRight, pos2(300., 300.), pos2(400., 340.)),
+            (pos2(400., 348.), Anchor::TopRight)
+        );
+        assert_eq!(
+            attach(Anchor::BottomRight, pos2(500., 550.), pos2(600., 590.)),
+            (pos2(600., 542.), Anchor::BottomRight)
+        );
+    }
+
+    #[test]
+    fn attach_centered() {
+        assert_eq!(
+            attach(Anchor::Center, pos2(0., 0.), pos2(100., 40.)),
+            (pos2(50., 20.), Anchor::Center)
+        );
+        assert_eq!(
+            attach(Anchor::TopCenter, pos2(300., 300.), pos2(400., 340.)),
+            (pos2(350., 292.), Anchor::BottomCenter)
+        );
+        assert_eq!(
+            attach(Anchor::TopCenter, pos2(300., 20.), pos2(400., 60.)),
+            (pos2(350., 68.), Anchor::TopCenter)
+        );
+        assert_eq!(
+            attach(Anchor::BottomCenter, pos2(300., 550.), pos2(400., 590.)),
+            (pos2(350., 542.), Anchor::BottomCenter)
+        );
+    }
+}
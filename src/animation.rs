@@ -0,0 +1,233 @@
+use crate::Anchor;
+use egui::{lerp, vec2, Vec2};
+use std::ops::{Add, Mul};
+
+/// Direction in which a toast slides out, it slides in from the opposite one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum SlideDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl SlideDirection {
+    const fn to_vec2(self) -> Vec2 {
+        match self {
+            Self::Left => vec2(-1., 0.),
+            Self::Right => vec2(1., 0.),
+            Self::Up => vec2(0., -1.),
+            Self::Down => vec2(0., 1.),
+        }
+    }
+}
+
+/// How toasts appear and disappear
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ToastAnimation {
+    /// Slide towards the closest edge of the anchor, centered anchors also fade.
+    #[default]
+    Auto,
+    /// Slide in the given direction.
+    Slide(SlideDirection),
+    /// Fade in and out.
+    Fade,
+    /// Grow from and shrink to the toast's center.
+    Scale,
+    /// Appear and disappear instantly.
+    None,
+}
+
+/// Value that moves linearly towards its target, like [`egui::Context::animate_value_with_time`]
+/// but stored on the toast so it goes away together with it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Tween<T> {
+    from: T,
+    to: T,
+    t: f32,
+}
+
+impl<T> Tween<T>
+where
+    T: Copy + PartialEq + Add<Output = T>,
+    f32: Mul<T, Output = T>,
+{
+    /// Starts at `value` without animating.
+    pub(crate) const fn new(value: T) -> Self {
+        Self {
+            from: value,
+            to: value,
+            t: 1.,
+        }
+    }
+
+    pub(crate) fn value(&self) -> T {
+        lerp(self.from..=self.to, self.t)
+    }
+
+    /// Moves `dt` seconds further towards `target` and returns the new value,
+    /// a changed target restarts the animation from the current value.
+    pub(crate) fn animate(&mut self, target: T, dt: f32, duration: f32) -> T {
+        if target != self.to {
+            *self = Self {
+                from: self.value(),
+                to: target,
+                t: 0.,
+            };
+        }
+        self.t = if duration > 0. {
+            (self.t + dt / duration).min(1.)
+        } else {
+            1.
+        };
+        self.value()
+    }
+
+    pub(crate) fn is_animating(&self) -> bool {
+        self.t < 1.
+    }
+}
+
+/// Offset, scale and opacity of a toast during its animation
+pub(crate) struct AnimationFrame {
+    pub offset: Vec2,
+    pub scale: f32,
+    pub opacity: f32,
+}
+
+impl ToastAnimation {
+    /// Replaces animations that move the toast with a fade.
+    pub(crate) const fn reduced(self) -> Self {
+        match self {
+            Self::Auto | Self::Slide(_) | Self::Scale => Self::Fade,
+            Self::Fade | Self::None => self,
+        }
+    }
+
+    /// Computes the animation frame of a toast of `size` that is `value` (eased, `0..=1`) shown.
+    pub(crate) fn frame(&self, anchor: Anchor, size: Vec2, value: f32) -> AnimationFrame {
+        let mut frame = AnimationFrame {
+            offset: Vec2::ZERO,
+            scale: 1.,
+            opacity: 1.,
+        };
+
+        match self {
+            Self::Auto => {
+                frame.offset = anchor.anim_dir() * size * (1. - value);
+                if anchor.fades() {
+                    frame.opacity = value;
+                }
+            }
+            Self::Slide(direction) => frame.offset = direction.to_vec2() * size * (1. - value),
+            Self::Fade => frame.opacity = value,
+            Self::Scale => frame.scale = value,
+            Self::None => {}
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: Vec2 = vec2(180., 40.);
+
+    fn frame(animation: ToastAnimation, anchor: Anchor, value: f32) -> (Vec2, f32, f32) {
+        let frame = animation.frame(anchor, SIZE, value);
+        (frame.offset, frame.scale, frame.opacity)
+    }
+
+    #[test]
+    fn tween() {
+        let mut tween = Tween::new(0.);
+        assert_eq!(tween.animate(0., 0.1, 0.2), 0.);
+        assert!(!tween.is_animating());
+
+        assert_eq!(tween.animate(10., 0.1, 0.2), 5.);
+        assert!(tween.is_animating());
+        // A new target is approached from the current value
+        assert_eq!(tween.animate(-5., 0.1, 0.2), 0.);
+        assert_eq!(tween.animate(-5., 0.1, 0.2), -5.);
+        assert!(!tween.is_animating());
+
+        // Without a duration the target is reached right away
+        assert_eq!(tween.animate(3., 0.1, 0.), 3.);
+    }
+
+    #[test]
+    fn auto_slides_towards_anchor() {
+        assert_eq!(
+            frame(ToastAnimation::Auto, Anchor::TopRight, 0.),
+            (vec2(180., 0.), 1., 1.)
+        );
+        assert_eq!(
+            frame(ToastAnimation::Auto, Anchor::BottomLeft, 0.5),
+            (vec2(-90., 0.), 1., 1.)
+        );
+        assert_eq!(
+            frame(ToastAnimation::Auto, Anchor::TopRight, 1.),
+            (Vec2::ZERO, 1., 1.)
+        );
+    }
+
+    #[test]
+    fn auto_fades_centered_anchors() {
+        assert_eq!(
+            frame(ToastAnimation::Auto, Anchor::TopCenter, 0.5),
+            (vec2(0., -20.), 1., 0.5)
+        );
+        assert_eq!(
+            frame(ToastAnimation::Auto, Anchor::BottomCenter, 0.5),
+            (vec2(0., 20.), 1., 0.5)
+        );
+        assert_eq!(
+            frame(ToastAnimation::Auto, Anchor::Center, 0.5),
+            (Vec2::ZERO, 1., 0.5)
+        );
+    }
+
+    #[test]
+    fn explicit_animations() {
+        let anchor = Anchor::TopRight;
+        assert_eq!(
+            frame(ToastAnimation::Slide(SlideDirection::Up), anchor, 0.5),
+            (vec2(0., -20.), 1., 1.)
+        );
+        assert_eq!(
+            frame(ToastAnimation::Fade, anchor, 0.25),
+            (Vec2::ZERO, 1., 0.25)
+        );
+        assert_eq!(
+            frame(ToastAnimation::Scale, anchor, 0.25),
+            (Vec2::ZERO, 0.25, 1.)
+        );
+        assert_eq!(
+            frame(ToastAnimation::None, anchor, 0.),
+            (Vec2::ZERO, 1., 1.)
+        );
+    }
+
+    #[test]
+    fn reduced_motion_only_fades() {
+        for animation in [
+            ToastAnimation::Auto,
+            ToastAnimation::Slide(SlideDirection::Left),
+            ToastAnimation::Scale,
+            ToastAnimation::Fade,
+        ] {
+            assert_eq!(animation.reduced(), ToastAnimation::Fade);
+        }
+        assert_eq!(ToastAnimation::None.reduced(), ToastAnimation::None);
+
+        // Nothing moves, whatever the anchor
+        for anchor in [Anchor::TopRight, Anchor::TopCenter, Anchor::CenterLeft] {
+            assert_eq!(
+                frame(ToastAnimation::Auto.reduced(), anchor, 0.5),
+                (Vec2::ZERO, 1., 0.5)
+            );
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::ToastId;
+
+/// Something that happened to a toast, see [`crate::Toasts::drain_events`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToastEvent {
+    /// Toast the event belongs to.
+    pub id: ToastId,
+    /// What happened.
+    pub kind: ToastEventKind,
+}
+
+/// Kind of a [`ToastEvent`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ToastEventKind {
+    /// The toast was shown for the first time.
+    Shown,
+    /// The body of the toast was clicked.
+    Clicked,
+    /// An action button was clicked, contains the key given to [`crate::Toast::action`].
+    Action(String),
+    /// The toast finished disappearing and was removed.
+    Dismissed(DismissReason),
+}
+
+/// Why a toast was dismissed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DismissReason {
+    /// The user clicked the close cross.
+    Closed,
+    /// The user swiped the toast away.
+    Swiped,
+    /// The duration of the toast ran out.
+    Expired,
+    /// The user clicked one of the action buttons.
+    Action,
+    /// [`crate::Toast::dismiss`] or one of the `Toasts::dismiss*` methods was called.
+    Programmatic,
+}
@@ -0,0 +1,208 @@
+use crate::{Toast, ToastLevel, ERROR_COLOR, INFO_COLOR, SUCCESS_COLOR, WARNING_COLOR};
+use egui::text::TextWrapping;
+use egui::{
+    Align, Color32, Context, FontId, FontSelection, Galley, Style, TextStyle, TextWrapMode, Vec2,
+    WidgetText,
+};
+use std::sync::Arc;
+
+/// Everything besides the toast itself and its maximum width that its galleys depend on,
+/// the same for all toasts of a frame
+#[derive(Clone)]
+pub(crate) struct GalleyKey {
+    pub style: Arc<Style>,
+    pub pixels_per_point: f32,
+    // egui returns the same galley for this text until it rebuilds its fonts,
+    // e.g. after `Context::set_fonts` or when the font atlas is full
+    pub fonts: Arc<Galley>,
+    pub padding: Vec2,
+}
+
+impl PartialEq for GalleyKey {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.style, &other.style)
+            && self.pixels_per_point == other.pixels_per_point
+            && Arc::ptr_eq(&self.fonts, &other.fonts)
+            && self.padding == other.padding
+    }
+}
+
+impl GalleyKey {
+    pub(crate) fn new(ctx: &Context, padding: Vec2) -> Self {
+        Self {
+            style: ctx.global_style(),
+            pixels_per_point: ctx.pixels_per_point(),
+            fonts: ctx.fonts_mut(|f| {
+                f.layout_no_wrap("egui_notify".into(), FontId::default(), Color32::WHITE)
+            }),
+            padding,
+        }
+    }
+}
+
+/// Laid out caption, icon and cross of a toast, reused across frames
+#[derive(Clone)]
+pub(crate) struct ToastGalleys {
+    key: GalleyKey,
+    max_width: f32,
+    pub caption: Arc<Galley>,
+    pub icon: Option<Arc<Galley>>,
+    pub cross: Option<Arc<Galley>>,
+    pub buttons: Vec<Arc<Galley>>,
+    pub icon_width: f32,
+}
+
+impl ToastGalleys {
+    /// Returns galleys of `toast`, laying them out again only if they are outdated.
+    pub(crate) fn get(ctx: &Context, toast: &mut Toast, key: &GalleyKey, max_width: f32) -> Self {
+        match &toast.galleys {
+            Some(galleys) if galleys.key == *key && galleys.max_width == max_width => {
+                galleys.clone()
+            }
+            _ => {
+                let galleys = Self::layout(ctx, toast, key.clone(), max_width);
+                toast.galleys = Some(galleys.clone());
+                galleys
+            }
+        }
+    }
+
+    fn layout(ctx: &Context, toast: &Toast, key: GalleyKey, max_width: f32) -> Self {
+        let no_wrap = TextWrapping::from_wrap_mode_and_width(TextWrapMode::Extend, f32::INFINITY);
+        let mut caption_galley =
+            layout_text(ctx, &toast.caption, no_wrap.clone(), FontSelection::Default);
+
+        let line_count = caption_galley.rows.len().max(1);
+        let icon_width = caption_galley.rect.height() / line_count as f32;
+
+        // Create toast icon
+        let icon_font = FontId::proportional(icon_width);
+        let icon_galley = match &toast.level {
+            ToastLevel::Info => {
+                Some(ctx.fonts_mut(|f| f.layout("ℹ".into(), icon_font, INFO_COLOR, f32::INFINITY)))
+            }
+            ToastLevel::Warning => Some(
+                ctx.fonts_mut(|f| f.layout("⚠".into(), icon_font, WARNING_COLOR, f32::INFINITY)),
+            ),
+            ToastLevel::Error => Some(
+                ctx.fonts_mut(|f| f.layout("！".into(), icon_font, ERROR_COLOR, f32::INFINITY)),
+            ),
+            ToastLevel::Success => Some(
+                ctx.fonts_mut(|f| f.layout("✅".into(), icon_font, SUCCESS_COLOR, f32::INFINITY)),
+            ),
+            ToastLevel::Custom(s, c) => {
+                Some(ctx.fonts_mut(|f| f.layout(s.clone(), icon_font, *c, f32::INFINITY)))
+            }
+            ToastLevel::None => None,
+        };
+
+        // Create closing cross
+        let cross_galley = if toast.closable {
+            let cross_fid = FontId::proportional(icon_width);
+            let cross_color = key.style.visuals.widgets.noninteractive.fg_stroke.color;
+            let cross_galley =
+                ctx.fonts_mut(|f| f.layout("❌".into(), cross_fid, cross_color, f32::INFINITY));
+            Some(cross_galley)
+        } else {
+            None
+        };
+
+        // Labels of action buttons
+        let buttons: Vec<_> = toast
+            .actions
+            .iter()
+            .map(|action| {
+                layout_text(
+                    ctx,
+                    &action.label,
+                    no_wrap.clone(),
+                    FontSelection::Style(TextStyle::Button),
+                )
+            })
+            .collect();
+
+        // Wrap the caption if the toast would be too wide
+        let cross_width = cross_galley.as_ref().map_or(0., |g| g.rect.width());
+        let (icon_width_padded, cross_width_padded) =
+            padded_widths(icon_width, cross_width, key.padding);
+        let buttons_width = buttons_width_padded(&buttons, &key.style, key.padding);
+        let extra_width = key
+            .padding
+            .x
+            .mul_add(2., icon_width_padded + buttons_width + cross_width_padded);
+        if caption_galley.rect.width() + extra_width > max_width {
+            caption_galley = layout_text(
+                ctx,
+                &toast.caption,
+                TextWrapping::wrap_at_width((max_width - extra_width).max(0.)),
+                FontSelection::Default,
+            );
+        }
+
+        Self {
+            key,
+            max_width,
+            caption: caption_galley,
+            icon: icon_galley,
+            cross: cross_galley,
+            buttons,
+            icon_width,
+        }
+    }
+
+    /// Sizes of the action buttons.
+    pub(crate) fn button_sizes(&self) -> impl Iterator<Item = Vec2> + '_ {
+        button_sizes(&self.buttons, &self.key.style)
+    }
+
+    /// Width taken up by the action buttons including their padding.
+    pub(crate) fn buttons_width_padded(&self) -> f32 {
+        buttons_width_padded(&self.buttons, &self.key.style, self.key.padding)
+    }
+}
+
+/// Widths taken up by the icon and the cross including their padding.
+pub(crate) fn padded_widths(icon_width: f32, cross_width: f32, padding: Vec2) -> (f32, f32) {
+    let icon_width_padded = if icon_width == 0. {
+        0.
+    } else {
+        icon_width + padding.x
+    };
+    let cross_width_padded = if cross_width == 0. {
+        0.
+    } else {
+        cross_width + padding.x
+    };
+    (icon_width_padded, cross_width_padded)
+}
+
+fn button_sizes<'a>(buttons: &'a [Arc<Galley>], style: &Style) -> impl Iterator<Item = Vec2> + 'a {
+    let button_padding = style.spacing.button_padding;
+    buttons
+        .iter()
+        .map(move |galley| galley.size() + 2. * button_padding)
+}
+
+fn buttons_width_padded(buttons: &[Arc<Galley>], style: &Style, padding: Vec2) -> f32 {
+    if buttons.is_empty() {
+        return 0.;
+    }
+
+    let spacing = style.spacing.item_spacing.x * (buttons.len() - 1) as f32;
+    button_sizes(buttons, style).map(|size| size.x).sum::<f32>() + spacing + padding.x
+}
+
+fn layout_text(
+    ctx: &Context,
+    text: &WidgetText,
+    wrapping: TextWrapping,
+    font: FontSelection,
+) -> Arc<Galley> {
+    text.clone().into_galley_impl(
+        ctx,
+        ctx.global_style().as_ref(),
+        wrapping,
+        font,
+        Align::LEFT,
+    )
+}
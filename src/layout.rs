@@ -0,0 +1,215 @@
+use crate::{Anchor, Toast};
+use egui::{lerp, vec2, Align, Vec2};
+
+// Distance by which each tucked toast of a deck peeks out
+const DECK_STEP: f32 = 8.;
+// Scale difference between adjacent toasts of a deck
+const DECK_SCALE_STEP: f32 = 0.05;
+// Number of tucked toasts that are visible behind the front one
+const DECK_DEPTH: usize = 3;
+
+/// How toasts are arranged
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLayout {
+    /// Toasts are shown one after another.
+    #[default]
+    List,
+    /// Older toasts are tucked behind the newest one and fan out into a list on hover.
+    Deck,
+}
+
+/// Where a single toast is drawn
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Placement {
+    /// Offset from the stack position.
+    pub offset: Vec2,
+    pub scale: f32,
+    pub opacity: f32,
+    /// Paint order, toasts with higher values are painted on top and take the input first.
+    pub z: usize,
+    /// Can the user interact with the toast?
+    pub interactive: bool,
+}
+
+impl ToastLayout {
+    /// Places every toast for which `shown` returns `true`, `fan_out` ranges from `0` for
+    /// a collapsed deck to `1` for a fanned out one.
+    /// Also returns the offset of the stack's end.
+    pub(crate) fn place(
+        &self,
+        toasts: &[Toast],
+        shown: impl Fn(&Toast) -> bool,
+        anchor: Anchor,
+        spacing: f32,
+        fan_out: f32,
+    ) -> (Vec<Option<Placement>>, Vec2) {
+        // Toasts are stacked away from the anchored edge
+        let dir = match anchor.align().y() {
+            Align::Max => vec2(0., -1.),
+            Align::Min | Align::Center => vec2(0., 1.),
+        };
+
+        let mut placements = vec![None; toasts.len()];
+        match self {
+            Self::List => {
+                let mut offset = 0.;
+                for (i, toast) in toasts.iter().enumerate() {
+                    if shown(toast) {
+                        placements[i] = Some(Placement {
+                            offset: dir * offset,
+                            scale: 1.,
+                            opacity: 1.,
+                            z: i,
+                            interactive: true,
+                        });
+                        offset += toast.height + spacing;
+                    }
+                }
+                (placements, dir * offset)
+            }
+            Self::Deck => {
+                // Newest toast is in front
+                let mut order: Vec<usize> =
+                    (0..toasts.len()).filter(|&i| shown(&toasts[i])).collect();
+                order.sort_unstable_by_key(|&i| std::cmp::Reverse(toasts[i].id));
+
+                let front_height = order.first().map_or(0., |&i| toasts[i].height);
+                let mut list_offset = 0.;
+                let mut deck_end = 0.;
+                for (depth, &i) in order.iter().enumerate() {
+                    let toast = &toasts[i];
+                    let tucked = depth.min(DECK_DEPTH) as f32;
+                    let deck_offset = front_height - toast.height + DECK_STEP * tucked;
+                    deck_end = front_height + DECK_STEP * tucked + spacing;
+
+                    placements[i] = Some(Placement {
+                        offset: dir * lerp(deck_offset..=list_offset, fan_out),
+                        scale: lerp((1. - DECK_SCALE_STEP * tucked)..=1., fan_out),
+                        opacity: if depth > DECK_DEPTH { fan_out } else { 1. },
+                        z: order.len() - depth,
+                        interactive: depth == 0 || fan_out >= 1.,
+                    });
+                    list_offset += toast.height + spacing;
+                }
+                (placements, dir * lerp(deck_end..=list_offset, fan_out))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPACING: f32 = 8.;
+
+    fn toasts(heights: &[f32]) -> Vec<Toast> {
+        heights
+            .iter()
+            .map(|&height| {
+                let mut toast = Toast::basic("");
+                toast.height = height;
+                toast
+            })
+            .collect()
+    }
+
+    fn offsets(placements: &[Option<Placement>]) -> Vec<Option<Vec2>> {
+        placements.iter().map(|p| p.map(|p| p.offset)).collect()
+    }
+
+    #[test]
+    fn list() {
+        let toasts = toasts(&[30., 40., 50.]);
+        let (placements, end) =
+            ToastLayout::List.place(&toasts, |_| true, Anchor::TopRight, SPACING, 0.);
+        assert_eq!(
+            offsets(&placements),
+            [Some(vec2(0., 0.)), Some(vec2(0., 38.)), Some(vec2(0., 86.))]
+        );
+        assert_eq!(end, vec2(0., 144.));
+        assert!(placements
+            .iter()
+            .flatten()
+            .all(|p| p.interactive && p.scale == 1.));
+
+        // Bottom anchored stacks grow upwards and skip toasts that are not shown
+        let (placements, end) = ToastLayout::List.place(
+            &toasts,
+            |toast| toast.height != 40.,
+            Anchor::BottomLeft,
+            SPACING,
+            0.,
+        );
+        assert_eq!(
+            offsets(&placements),
+            [Some(vec2(0., 0.)), None, Some(vec2(0., -38.))]
+        );
+        assert_eq!(end, vec2(0., -96.));
+    }
+
+    #[test]
+    fn deck_collapsed() {
+        let toasts = toasts(&[30., 40., 50.]);
+        let (placements, end) =
+            ToastLayout::Deck.place(&toasts, |_| true, Anchor::TopRight, SPACING, 0.);
+        // Newest toast is in front, older ones peek out below it
+        assert_eq!(
+            offsets(&placements),
+            [Some(vec2(0., 36.)), Some(vec2(0., 18.)), Some(vec2(0., 0.))]
+        );
+        assert_eq!(end, vec2(0., 74.));
+
+        let scales: Vec<_> = placements.iter().flatten().map(|p| p.scale).collect();
+        assert_eq!(
+            scales,
+            [1. - 2. * DECK_SCALE_STEP, 1. - DECK_SCALE_STEP, 1.]
+        );
+        let interactive: Vec<_> = placements.iter().flatten().map(|p| p.interactive).collect();
+        assert_eq!(interactive, [false, false, true]);
+        let z: Vec<_> = placements.iter().flatten().map(|p| p.z).collect();
+        assert_eq!(z, [1, 2, 3]);
+    }
+
+    #[test]
+    fn deck_puts_newest_on_top() {
+        // Toasts added in reverse order
+        let mut toasts = toasts(&[30., 40., 50.]);
+        toasts.reverse();
+        let (placements, _) =
+            ToastLayout::Deck.place(&toasts, |_| true, Anchor::TopRight, SPACING, 0.);
+        let z: Vec<_> = placements.iter().flatten().map(|p| p.z).collect();
+        assert_eq!(z, [3, 2, 1]);
+        assert_eq!(placements[2].map(|p| p.offset), Some(vec2(0., 36.)));
+    }
+
+    #[test]
+    fn deck_fanned_out() {
+        let toasts = toasts(&[30., 40., 50.]);
+        let (placements, end) =
+            ToastLayout::Deck.place(&toasts, |_| true, Anchor::BottomRight, SPACING, 1.);
+        // Laid out as a list from the newest toast
+        assert_eq!(
+            offsets(&placements),
+            [
+                Some(vec2(0., -106.)),
+                Some(vec2(0., -58.)),
+                Some(vec2(0., 0.))
+            ]
+        );
+        assert_eq!(end, vec2(0., -144.));
+        assert!(placements
+            .iter()
+            .flatten()
+            .all(|p| p.interactive && p.scale == 1. && p.opacity == 1.));
+    }
+
+    #[test]
+    fn deck_hides_deep_toasts() {
+        let toasts = toasts(&[30.; DECK_DEPTH + 2]);
+        let (placements, _) =
+            ToastLayout::Deck.place(&toasts, |_| true, Anchor::TopRight, SPACING, 0.);
+        let opacities: Vec<_> = placements.iter().flatten().map(|p| p.opacity).collect();
+        assert_eq!(opacities, [0., 1., 1., 1., 1.]);
+    }
+}
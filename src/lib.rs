@@ -6,18 +6,44 @@ mod toast;
 pub use toast::*;
 mod anchor;
 pub use anchor::*;
+mod layout;
+pub use layout::*;
+mod animation;
+pub use animation::*;
+mod event;
+pub use event::*;
+mod galleys;
+use animation::Tween;
+use galleys::{padded_widths, GalleyKey, ToastGalleys};
+mod sender;
+pub use sender::*;
+mod result;
+pub use result::*;
+#[cfg(feature = "log")]
+pub mod log;
+#[cfg(feature = "tracing")]
+pub mod tracing;
 
 #[doc(hidden)]
 pub use egui::__run_test_ctx;
-use egui::text::TextWrapping;
+use egui::emath::{easing, TSTransform};
 use egui::{
-    vec2, Align, Color32, Context, CornerRadius, FontId, FontSelection, Id, LayerId, Order, Rect,
-    Shadow, Stroke, TextWrapMode, Vec2, WidgetText,
+    vec2, Align, Area, Button, Color32, Context, CornerRadius, FontId, Galley, Id, LayerId, Order,
+    Painter, Pos2, Rect, Sense, Shadow, Shape, Stroke, TextStyle, UiBuilder, Vec2, ViewportId,
+    WidgetText,
 };
+use std::sync::Arc;
 
 pub(crate) const TOAST_WIDTH: f32 = 180.;
 pub(crate) const TOAST_HEIGHT: f32 = 34.;
 
+/// Toasts dragged further than this part of their width are dismissed when released.
+const SWIPE_DISTANCE: f32 = 0.5;
+/// Toasts released faster than this, in points per second, are dismissed.
+const SWIPE_VELOCITY: f32 = 500.;
+/// Events kept for [`Toasts::drain_events`], older ones are dropped.
+const MAX_EVENTS: usize = 256;
+
 const ERROR_COLOR: Color32 = Color32::from_rgb(200, 90, 90);
 const INFO_COLOR: Color32 = Color32::from_rgb(150, 200, 210);
 const WARNING_COLOR: Color32 = Color32::from_rgb(230, 220, 140);
@@ -47,7 +73,17 @@ pub struct Toasts {
     speed: f32,
     font: Option<FontId>,
     shadow: Option<Shadow>,
-    held: bool,
+    sender: Option<ToastSender>,
+    max_visible: Option<usize>,
+    expanded: bool,
+    layout: ToastLayout,
+    max_width: Option<f32>,
+    avoid_panels: bool,
+    animation: ToastAnimation,
+    easing: fn(f32) -> f32,
+    reduced_motion: Option<bool>,
+    clock: Option<Box<dyn Fn() -> f64 + Send + Sync>>,
+    events: Vec<ToastEvent>,
 }
 
 impl Toasts {
@@ -60,11 +96,21 @@ impl Toasts {
             toasts: vec![],
             spacing: 8.,
             padding: vec2(10., 10.),
-            held: false,
             speed: 4.,
             reverse: false,
             font: None,
             shadow: None,
+            sender: None,
+            max_visible: None,
+            expanded: false,
+            layout: ToastLayout::List,
+            max_width: None,
+            avoid_panels: false,
+            animation: ToastAnimation::Auto,
+            easing: easing::cubic_out,
+            reduced_motion: None,
+            clock: None,
+            events: vec![],
         }
     }
 
@@ -102,6 +148,90 @@ impl Toasts {
         }
     }
 
+    /// Dismisses the toast with the given `id`, does nothing if it is already gone.
+    pub fn dismiss(&mut self, id: ToastId) {
+        if let Some(toast) = self.get_mut(id) {
+            toast.dismiss();
+        }
+    }
+
+    /// Returns the toast with the given `id` if it is still in the collection.
+    /// Toasts added through a [`ToastSender`] are only returned once [`Toasts::show`] or
+    /// [`Toasts::get_mut`] moved them into the collection, see [`Toasts::is_pending`].
+    pub fn get(&self, id: ToastId) -> Option<&Toast> {
+        self.toasts.iter().find(|toast| toast.id == id)
+    }
+
+    /// Returns the toast with the given `id` if it is still in the collection.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default();
+    /// let id = t.info("Uploading...").duration(None).id();
+    /// // Some frames later
+    /// if let Some(toast) = t.get_mut(id) {
+    ///     toast.closable(true);
+    /// }
+    /// ```
+    pub fn get_mut(&mut self, id: ToastId) -> Option<&mut Toast> {
+        if self.is_pending(id) {
+            self.add_pending();
+        }
+        self.toasts.iter_mut().find(|toast| toast.id == id)
+    }
+
+    /// Returns `true` if the toast with the given `id` is still in the collection,
+    /// this includes pending toasts and toasts that are playing their disappear animation.
+    pub fn contains(&self, id: ToastId) -> bool {
+        self.get(id).is_some() || self.is_pending(id)
+    }
+
+    /// Returns `true` if the toast with the given `id` was added through a [`ToastSender`]
+    /// and waits to be moved into the collection by the next [`Toasts::show`].
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default();
+    /// let id = t.sender().info("Download finished");
+    /// assert!(t.is_pending(id) && !t.is_expired(id));
+    /// ```
+    pub fn is_pending(&self, id: ToastId) -> bool {
+        self.sender
+            .as_ref()
+            .is_some_and(|sender| sender.is_pending(id))
+    }
+
+    /// Returns `true` if the toast with the given `id` has expired or was dismissed,
+    /// i.e. it is disappearing or already removed from the collection.
+    /// Pending toasts are not expired.
+    pub fn is_expired(&self, id: ToastId) -> bool {
+        match self.get(id) {
+            Some(toast) => toast.state.disappearing() || toast.state.disappeared(),
+            None => !self.is_pending(id),
+        }
+    }
+
+    /// Takes all events that happened since the last call, see [`ToastEvent`].
+    /// Events are collected by [`Toasts::show`] until they are drained, only the last 256 are
+    /// kept so apps that never drain them don't pile them up.
+    /// ```
+    /// use egui_notify::{DismissReason, ToastEventKind, Toasts};
+    ///
+    /// let mut t = Toasts::default();
+    /// let id = t.info("Build failed").id();
+    /// // After `t.show(ctx)`
+    /// for event in t.drain_events() {
+    ///     match event.kind {
+    ///         ToastEventKind::Clicked if event.id == id => { /* Open the build log */ }
+    ///         ToastEventKind::Dismissed(DismissReason::Expired) => {}
+    ///         _ => {}
+    ///     }
+    /// }
+    /// ```
+    pub fn drain_events(&mut self) -> Vec<ToastEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     /// Returns the number of toast items.
     pub fn len(&self) -> usize {
         self.toasts.len()
@@ -150,6 +280,12 @@ impl Toasts {
         ))
     }
 
+    /// Returns a [`ToastSender`] that can add toasts from other threads.
+    /// All senders share the same queue, which is drained in [`Toasts::show`].
+    pub fn sender(&mut self) -> ToastSender {
+        self.sender.get_or_insert_with(ToastSender::default).clone()
+    }
+
     /// Should toasts be added in reverse order?
     pub const fn reverse(mut self, reverse: bool) -> Self {
         self.reverse = reverse;
@@ -186,6 +322,64 @@ impl Toasts {
         self
     }
 
+    /// Limits the number of toasts shown at once, the rest waits in a queue and appears as shown
+    /// toasts leave. Queued toasts don't expire, a clickable "+N more" indicator is shown instead.
+    pub const fn with_max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = Some(max_visible);
+        self
+    }
+
+    /// How toasts are arranged, see [`ToastLayout`].
+    pub const fn with_layout(mut self, layout: ToastLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Maximum width of toasts' boxes, longer captions are wrapped.
+    /// Toasts never get wider than the available space minus margins.
+    pub const fn with_max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Should toasts be anchored to the space left after panels instead of the whole screen?
+    /// [`Toasts::show`] has to be called after all panels were added for this to work.
+    pub const fn with_avoid_panels(mut self, avoid_panels: bool) -> Self {
+        self.avoid_panels = avoid_panels;
+        self
+    }
+
+    /// How toasts appear and disappear, see [`ToastAnimation`].
+    pub const fn with_animation(mut self, animation: ToastAnimation) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// Easing curve of appear and disappear animations, e.g. one from [`egui::emath::easing`].
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let t = Toasts::default().with_easing(egui::emath::easing::back_out);
+    /// ```
+    pub const fn with_easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Should motion be reduced? Toasts fade instead of moving and the stack doesn't animate.
+    /// By default motion is reduced if egui's `animation_time` is zero.
+    pub const fn with_reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = Some(reduced_motion);
+        self
+    }
+
+    /// Replaces the clock toasts expire by, it should return the current time in seconds.
+    /// By default [`egui::InputState::time`] is used.
+    pub fn with_clock(mut self, clock: impl Fn() -> f64 + Send + Sync + 'static) -> Self {
+        self.clock = Some(Box::new(clock));
+        self
+    }
+
     /// Changes the default font used for all toasts.
     pub fn with_default_font(mut self, font: FontId) -> Self {
         self.font = Some(font);
@@ -196,111 +390,274 @@ impl Toasts {
 impl Toasts {
     /// Displays toast queue
     pub fn show(&mut self, ctx: &Context) {
-        let Self {
+        let screen = self.screen_rect(ctx);
+        let pos = self.anchor.screen_corner(screen, self.margin);
+        self.show_stack(ctx, &screen_painter(ctx), screen, pos, self.anchor);
+    }
+
+    /// Displays toast queue inside of `ui`, toasts are anchored to [`egui::Ui::max_rect`]
+    /// and painted on the layer of `ui`, clipped to its bounds.
+    pub fn show_in(&mut self, ui: &egui::Ui) {
+        let bounds = ui.max_rect();
+        let pos = self.anchor.screen_corner(bounds, self.margin);
+        self.show_stack(ui.ctx(), &ui.painter_at(bounds), bounds, pos, self.anchor);
+    }
+
+    /// Displays toast queue next to `rect`, e.g. [`egui::Response::rect`] of a button.
+    ///
+    /// `anchor` selects the side of `rect` the stack is placed at: [`Anchor::TopRight`] puts it
+    /// above `rect` aligned to its right edge, [`Anchor::CenterLeft`] to the left of `rect`, etc.
+    /// The stack is flipped to the opposite side if it would go off screen.
+    pub fn show_at(&mut self, ctx: &Context, rect: Rect, anchor: Anchor) {
+        let screen = self.screen_rect(ctx);
+        let (pos, anchor) = anchor.attach_to(
+            rect,
+            self.stack_size(ctx.viewport_id()),
+            screen,
+            self.margin,
+        );
+        self.show_stack(ctx, &screen_painter(ctx), screen, pos, anchor);
+    }
+
+    /// Moves toasts queued by senders into the collection.
+    fn add_pending(&mut self) {
+        if let Some(sender) = &self.sender {
+            for toast in sender.take() {
+                self.add(toast);
+            }
+        }
+    }
+
+    /// Rect toasts are anchored to when shown on the whole screen.
+    fn screen_rect(&self, ctx: &Context) -> Rect {
+        if self.avoid_panels {
+            ctx.available_rect()
+        } else {
+            ctx.input(|i| i.content_rect())
+        }
+    }
+
+    /// Id of the first toast of `viewport` that waits in the overflow queue.
+    /// Toasts are shown oldest first, so every toast with an id at least as large is queued.
+    fn overflow_start(&self, viewport: ViewportId) -> Option<ToastId> {
+        let max_visible = self.max_visible.filter(|_| !self.expanded)?;
+        let mut ids: Vec<ToastId> = self
+            .toasts
+            .iter()
+            .filter(|toast| toast.viewport_id() == viewport)
+            .map(Toast::id)
+            .collect();
+        ids.sort_unstable();
+        ids.get(max_visible).copied()
+    }
+
+    /// Size of the visible stack in `viewport` as of the last frame.
+    fn stack_size(&self, viewport: ViewportId) -> Vec2 {
+        let overflow_start = self.overflow_start(viewport);
+        let toasts = || {
+            self.toasts.iter().filter(move |toast| {
+                toast.viewport_id() == viewport && overflow_start.is_none_or(|id| toast.id < id)
+            })
+        };
+        let width = toasts().map(|toast| toast.width).fold(0., f32::max);
+        let height: f32 = toasts().map(|toast| toast.height + self.spacing).sum();
+        vec2(width, (height - self.spacing).max(0.))
+    }
+
+    /// Shows toasts of the current viewport, `bounds` limits the width of toasts.
+    fn show_stack(
+        &mut self,
+        ctx: &Context,
+        painter: &Painter,
+        bounds: Rect,
+        mut pos: Pos2,
+        anchor: Anchor,
+    ) {
+        if let Some(sender) = &self.sender {
+            for toast in sender.drain(ctx) {
+                self.add(toast);
+            }
+        }
+
+        let viewport = ctx.viewport_id();
+        let anim_time = ctx.global_style().animation_time;
+        let reduced_motion = self.reduced_motion.unwrap_or(anim_time <= 0.);
+        // Layout changes are animated unless motion is reduced
+        let animate = |id: Id, value: f32| {
+            if reduced_motion {
+                value
+            } else {
+                ctx.animate_value_with_time(id, value, anim_time)
+            }
+        };
+        // Toasts animate their own position and size, stored on them instead of in egui's memory
+        let tween_time = if reduced_motion { 0. } else { anim_time };
+        let dt = ctx.input(|i| i.stable_dt);
+        let stack_id = Id::new("egui_notify_stack")
+            .with(painter.layer_id())
+            .with(viewport);
+
+        if anchor.align().y() == Align::Center {
+            pos.y -= animate(stack_id.with("center"), self.stack_size(viewport).y / 2.);
+        }
+
+        // Collapse the expanded stack once the overflow queue is gone
+        if self.expanded {
+            let count = self
+                .toasts
+                .iter()
+                .filter(|toast| toast.viewport_id() == viewport)
+                .count();
+            self.expanded = self.max_visible.is_some_and(|max| count > max);
+        }
+        let overflow_start = self.overflow_start(viewport);
+
+        let hover_pos = ctx.input(|i| i.pointer.hover_pos());
+
+        // Deck fans out while hovered, hover state is stored for the next frame
+        let deck_id = stack_id.with("deck");
+        let fan_out = match self.layout {
+            ToastLayout::List => 1.,
+            ToastLayout::Deck => {
+                let hovered = ctx.data(|d| d.get_temp(deck_id)).unwrap_or(false);
+                animate(deck_id, if hovered { 1. } else { 0. })
+            }
+        };
+        let (placements, stack_end) = self.layout.place(
+            &self.toasts,
+            |toast| {
+                toast.viewport_id() == viewport && overflow_start.is_none_or(|id| toast.id < id)
+            },
             anchor,
+            self.spacing,
+            fan_out,
+        );
+        let stack_end = vec2(stack_end.x, animate(stack_id.with("end"), stack_end.y));
+        let mut stack_rect = Rect::NOTHING;
+
+        let Self {
             margin,
-            spacing,
             padding,
+            max_width,
             toasts,
-            held,
             speed,
+            expanded,
+            animation,
+            easing,
+            clock,
+            events,
             ..
         } = self;
 
-        let mut pos = anchor.screen_corner(ctx.input(|i| i.content_rect().max), *margin);
-        let p = ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("toasts")));
-
-        // `held` used to prevent sticky removal
-        if ctx.input(|i| i.pointer.primary_released()) {
-            *held = false;
-        }
+        let overflow_count = overflow_start.map_or(0, |start| {
+            toasts
+                .iter()
+                .filter(|toast| toast.viewport_id() == viewport && toast.id >= start)
+                .count()
+        });
 
         let visuals = ctx.global_style().visuals.widgets.noninteractive;
         let mut update = false;
+        let mut next_expiry = None;
+        let now = clock
+            .as_ref()
+            .map_or_else(|| ctx.input(|i| i.time), |clock| clock());
 
-        toasts.retain_mut(|toast| {
-            // Start disappearing expired toasts
-            if let Some((_initial_d, current_d)) = toast.duration {
-                if current_d <= 0. {
-                    toast.state = ToastState::Disappear;
+        // Style and fonts are the same for every toast, their galleys only differ in width
+        let galley_key = GalleyKey::new(ctx, *padding);
+
+        // Toasts are laid out first, so the stack's area can be placed around them
+        let mut frames = vec![];
+        for (index, (toast, placement)) in toasts.iter_mut().zip(placements).enumerate() {
+            // Toasts of other viewports are shown when `show` is called from there
+            if toast.viewport_id() != viewport {
+                if toast.state.appearing() {
+                    ctx.request_repaint_of(toast.viewport_id());
                 }
+                continue;
             }
 
-            let anim_offset = toast.width * (1. - ease_in_cubic(toast.value));
-            pos.x += anim_offset * anchor.anim_side();
-            let rect = toast.calc_anchored_rect(pos, *anchor);
+            // Queued toasts are neither shown nor ticking
+            let Some(placement) = placement else {
+                toast.last_tick = None;
+                continue;
+            };
 
-            if let Some((_, d)) = toast.duration.as_mut() {
-                // Check if we hover over the toast and if true don't decrease the duration
-                let hover_pos = ctx.input(|i| i.pointer.hover_pos());
-                let is_outside_rect = hover_pos.is_none_or(|pos| !rect.contains(pos));
+            if !toast.shown {
+                toast.shown = true;
+                events.push(ToastEvent {
+                    id: toast.id,
+                    kind: ToastEventKind::Shown,
+                });
+            }
 
-                if is_outside_rect && toast.state.idling() {
-                    *d -= ctx.input(|i| i.stable_dt);
-                    update = true;
+            // Start disappearing expired toasts
+            if let Some((_initial_d, current_d)) = toast.duration {
+                if current_d <= 0. {
+                    toast.dismiss_with(DismissReason::Expired);
                 }
             }
 
-            let caption_galley = toast.caption.clone().into_galley_impl(
-                ctx,
-                ctx.global_style().as_ref(),
-                TextWrapping::from_wrap_mode_and_width(TextWrapMode::Extend, f32::INFINITY),
-                FontSelection::Default,
-                Align::LEFT,
+            let mut animation = toast.animation.unwrap_or(*animation);
+            if reduced_motion {
+                animation = animation.reduced();
+            }
+            let anim =
+                animation.frame(anchor, vec2(toast.width, toast.height), easing(toast.value));
+            // Slide towards the target position instead of jumping when the stack changes
+            let tween = toast.offset.get_or_insert(Tween::new(placement.offset.y));
+            let offset = vec2(
+                placement.offset.x,
+                tween.animate(placement.offset.y, dt, tween_time),
             );
+            update |= tween.is_animating();
+
+            // Follow the pointer while dragged and spring back once released
+            let drag = match toast.drag {
+                Some(drag) => {
+                    // The spring back starts from here
+                    toast.drag_spring = Tween::new(drag);
+                    drag
+                }
+                None => {
+                    let drag = toast.drag_spring.animate(0., dt, tween_time);
+                    update |= toast.drag_spring.is_animating();
+                    drag
+                }
+            };
+            let rect = toast
+                .calc_anchored_rect(pos + offset, anchor)
+                .translate(anim.offset + vec2(drag, 0.));
+            let scale = placement.scale * anim.scale;
+            let scaling = TSTransform::new(rect.center().to_vec2() * (1. - scale), scale);
+            stack_rect |= scaling * rect;
+            let opacity = placement.opacity * anim.opacity;
+            let mut shapes = vec![];
+
+            let max_width = toast
+                .max_width
+                .or(*max_width)
+                .unwrap_or(f32::INFINITY)
+                .min(margin.x.mul_add(-2., bounds.width()));
+            let galleys = ToastGalleys::get(ctx, toast, &galley_key, max_width);
+            let buttons_width_padded = galleys.buttons_width_padded();
+            let buttons_height = galleys.button_sizes().map(|size| size.y).fold(0., f32::max);
+            let ToastGalleys {
+                caption: caption_galley,
+                icon: icon_galley,
+                cross: cross_galley,
+                icon_width,
+                ..
+            } = galleys.clone();
 
             let (caption_width, caption_height) =
                 (caption_galley.rect.width(), caption_galley.rect.height());
-
-            let line_count = caption_galley.rows.len().max(1);
-            let icon_width = caption_height / line_count as f32;
             let rounding = CornerRadius::same(4);
 
-            // Create toast icon
-            let icon_font = FontId::proportional(icon_width);
-            let icon_galley =
-                match &toast.level {
-                    ToastLevel::Info => {
-                        Some(ctx.fonts_mut(|f| {
-                            f.layout("ℹ".into(), icon_font, INFO_COLOR, f32::INFINITY)
-                        }))
-                    }
-                    ToastLevel::Warning => Some(ctx.fonts_mut(|f| {
-                        f.layout("⚠".into(), icon_font, WARNING_COLOR, f32::INFINITY)
-                    })),
-                    ToastLevel::Error => Some(ctx.fonts_mut(|f| {
-                        f.layout("！".into(), icon_font, ERROR_COLOR, f32::INFINITY)
-                    })),
-                    ToastLevel::Success => Some(ctx.fonts_mut(|f| {
-                        f.layout("✅".into(), icon_font, SUCCESS_COLOR, f32::INFINITY)
-                    })),
-                    ToastLevel::Custom(s, c) => {
-                        Some(ctx.fonts_mut(|f| f.layout(s.clone(), icon_font, *c, f32::INFINITY)))
-                    }
-                    ToastLevel::None => None,
-                };
-
             let (action_width, action_height) =
                 icon_galley.as_ref().map_or((0., 0.), |icon_galley| {
                     (icon_galley.rect.width(), icon_galley.rect.height())
                 });
-
-            // Create closing cross
-            let cross_galley = if toast.closable {
-                let cross_fid = FontId::proportional(icon_width);
-                let cross_galley = ctx.fonts_mut(|f| {
-                    f.layout(
-                        "❌".into(),
-                        cross_fid,
-                        visuals.fg_stroke.color,
-                        f32::INFINITY,
-                    )
-                });
-                Some(cross_galley)
-            } else {
-                None
-            };
-
             let (cross_width, cross_height) =
                 cross_galley.as_ref().map_or((0., 0.), |cross_galley| {
                     (cross_galley.rect.width(), cross_galley.rect.height())
@@ -308,36 +665,35 @@ impl Toasts {
 
             let icon_x_padding = (0., padding.x);
             let cross_x_padding = (padding.x, 0.);
+            let (icon_width_padded, cross_width_padded) =
+                padded_widths(icon_width, cross_width, *padding);
 
-            let icon_width_padded = if icon_width == 0. {
-                0.
-            } else {
-                icon_width + icon_x_padding.0 + icon_x_padding.1
-            };
-            let cross_width_padded = if cross_width == 0. {
-                0.
-            } else {
-                cross_width + cross_x_padding.0 + cross_x_padding.1
-            };
-
-            toast.width = padding
-                .x
-                .mul_add(2., icon_width_padded + caption_width + cross_width_padded);
-            toast.height = padding
-                .y
-                .mul_add(2., action_height.max(caption_height).max(cross_height));
-
-            // Required due to positioning of the next toast
-            pos.x -= anim_offset * anchor.anim_side();
+            // Animate size changes, e.g. after the caption was updated
+            let size = vec2(
+                padding.x.mul_add(
+                    2.,
+                    icon_width_padded + caption_width + buttons_width_padded + cross_width_padded,
+                ),
+                padding.y.mul_add(
+                    2.,
+                    action_height
+                        .max(caption_height)
+                        .max(cross_height)
+                        .max(buttons_height),
+                ),
+            );
+            let tween = toast.size.get_or_insert(Tween::new(size));
+            let size = tween.animate(size, dt, tween_time);
+            update |= tween.is_animating();
+            (toast.width, toast.height) = (size.x, size.y);
 
             // Draw shadow
             if let Some(shadow) = self.shadow {
-                let s = shadow.as_shape(rect, rounding);
-                p.add(s);
+                shapes.push(shadow.as_shape(rect, rounding).into());
             }
 
             // Draw background
-            p.rect_filled(rect, rounding, visuals.bg_fill);
+            shapes.push(Shape::rect_filled(rect, rounding, visuals.bg_fill));
 
             // Paint icon
             if let Some((icon_galley, true)) =
@@ -345,11 +701,11 @@ impl Toasts {
             {
                 let oy = toast.height / 2. - action_height / 2.;
                 let ox = padding.x + icon_x_padding.0;
-                p.galley(
+                shapes.push(Shape::galley(
                     rect.min + vec2(ox, oy),
                     icon_galley,
                     visuals.fg_stroke.color,
-                );
+                ));
             }
 
             // Paint caption
@@ -360,34 +716,250 @@ impl Toasts {
                 action_width + icon_x_padding.1
             };
             let o_from_cross = if cross_width == 0. {
-                0.
+                buttons_width_padded
             } else {
-                cross_width + cross_x_padding.0
+                cross_width + cross_x_padding.0 + buttons_width_padded
             };
             let ox = (toast.width / 2. - caption_width / 2.) + o_from_icon / 2. - o_from_cross / 2.;
-            p.galley(
+            shapes.push(Shape::galley(
                 rect.min + vec2(ox, oy),
                 caption_galley,
                 visuals.fg_stroke.color,
-            );
+            ));
 
             // Paint cross
+            let mut cross_rect = None;
             if let Some(cross_galley) = cross_galley {
-                let cross_rect = cross_galley.rect;
                 let oy = toast.height / 2. - cross_height / 2.;
                 let ox = toast.width - cross_width - cross_x_padding.1 - padding.x;
                 let cross_pos = rect.min + vec2(ox, oy);
-                p.galley(cross_pos, cross_galley, Color32::BLACK);
+                cross_rect = Some(Rect::from_min_size(
+                    cross_pos,
+                    cross_galley.rect.max.to_vec2(),
+                ));
+                shapes.push(Shape::galley(cross_pos, cross_galley, Color32::BLACK));
+            }
+
+            // Action buttons are only added while the toast is at full size
+            let mut button_rects = vec![];
+            if placement.interactive && scale >= 1. {
+                let mut x = toast.width - cross_width_padded - buttons_width_padded;
+                for size in galleys.button_sizes() {
+                    let oy = toast.height / 2. - size.y / 2.;
+                    button_rects.push(Rect::from_min_size(rect.min + vec2(x, oy), size));
+                    x += size.x + ctx.global_style().spacing.item_spacing.x;
+                }
+            }
+
+            frames.push(ToastFrame {
+                index,
+                id: toast.id,
+                z: placement.z,
+                interactive: placement.interactive,
+                animation,
+                rect,
+                scaling,
+                opacity,
+                drag,
+                shapes,
+                cross: cross_rect,
+                buttons: button_rects
+                    .into_iter()
+                    .zip(galleys.buttons.iter().cloned())
+                    .collect(),
+            });
+        }
+
+        if self.layout == ToastLayout::Deck {
+            let hovered = hover_pos.is_some_and(|pos| stack_rect.contains(pos));
+            ctx.data_mut(|d| d.insert_temp(deck_id, hovered));
+        }
+
+        // The whole stack is one area, toasts are painted and take the input in their z order.
+        // Toasts inside a `Ui` are kept right above the `Ui`'s layer.
+        let layer_id = LayerId::new(painter.layer_id().order, stack_id);
+        if painter.layer_id() != screen_layer() {
+            ctx.set_sublayer(painter.layer_id(), layer_id);
+        }
+        let stack_painter = painter.clone().with_layer_id(layer_id);
+
+        // Indicator of queued toasts, expands the stack when clicked
+        let more_rect = (overflow_count > 0).then(|| {
+            // Resolved before locking the fonts, which also locks the context
+            let font = TextStyle::Small.resolve(&ctx.global_style());
+            let galley = ctx.fonts_mut(|f| {
+                f.layout_no_wrap(
+                    format!("+{overflow_count} more"),
+                    font,
+                    visuals.fg_stroke.color,
+                )
+            });
+            let size = galley.size() + *padding;
+            let rect = anchor.toast_align().anchor_size(pos + stack_end, size);
+            let rounding = CornerRadius::same((size.y / 2.) as u8);
+
+            if let Some(shadow) = self.shadow {
+                stack_painter.add(shadow.as_shape(rect, rounding));
+            }
+            stack_painter.rect_filled(rect, rounding, visuals.bg_fill);
+            stack_painter.galley(
+                rect.center() - galley.size() / 2.,
+                galley,
+                visuals.fg_stroke.color,
+            );
+            rect
+        });
+
+        frames.sort_by_key(|frame| frame.z);
+        let area_rect = frames
+            .iter()
+            .map(|frame| frame.scaling * frame.rect)
+            .chain(more_rect)
+            .fold(Rect::NOTHING, Rect::union);
+        let (responses, more_clicked) = if area_rect.is_negative() {
+            (vec![], false)
+        } else {
+            Area::new(stack_id)
+                .order(layer_id.order)
+                .fixed_pos(area_rect.min)
+                .constrain_to(painter.clip_rect())
+                .constrain(false)
+                .movable(false)
+                .sense(Sense::hover())
+                .show(ctx, |ui| {
+                    ui.set_clip_rect(painter.clip_rect());
+                    ui.expand_to_include_rect(area_rect);
+
+                    let responses: Vec<_> = frames
+                        .iter()
+                        .map(|frame| {
+                            // The toast is painted below its widgets, above the toasts before it
+                            let shape_idx = stack_painter.add(Shape::Noop);
+                            let toast_id = Id::new("egui_notify_toast").with(frame.id);
+                            let rect = frame.scaling * frame.rect;
+                            let builder = UiBuilder::new().id_salt(toast_id).max_rect(rect);
+                            ui.scope_builder(builder, |ui| {
+                                ui.set_opacity(frame.opacity);
+
+                                // Toasts behind the front of a deck only block the pointer
+                                let sense = if frame.interactive {
+                                    Sense::click_and_drag()
+                                } else {
+                                    Sense::hover()
+                                };
+                                let response = ui.interact(rect, toast_id, sense);
+
+                                let cross_clicked = frame.interactive
+                                    && frame.cross.is_some_and(|cross| {
+                                        ui.interact(
+                                            frame.scaling * cross,
+                                            toast_id.with("cross"),
+                                            Sense::click(),
+                                        )
+                                        .clicked()
+                                    });
 
-                let screen_cross = Rect {
-                    max: cross_pos + cross_rect.max.to_vec2(),
-                    min: cross_pos,
-                };
+                                let mut action = None;
+                                for (index, (rect, label)) in frame.buttons.iter().enumerate() {
+                                    if ui
+                                        .put(*rect, Button::new(WidgetText::Galley(label.clone())))
+                                        .clicked()
+                                    {
+                                        action = Some(index);
+                                    }
+                                }
 
-                if let Some(pos) = ctx.input(|i| i.pointer.press_origin()) {
-                    if screen_cross.contains(pos) && !*held {
-                        toast.dismiss();
-                        *held = true;
+                                (shape_idx, response, cross_clicked, action)
+                            })
+                            .inner
+                        })
+                        .collect();
+
+                    let more_clicked = more_rect.is_some_and(|rect| {
+                        ui.interact(rect, stack_id.with("more"), Sense::click())
+                            .clicked()
+                    });
+                    (responses, more_clicked)
+                })
+                .inner
+        };
+
+        if more_clicked {
+            *expanded = true;
+            update = true;
+        }
+
+        for (frame, (shape_idx, response, cross_clicked, action)) in
+            frames.into_iter().zip(responses)
+        {
+            let ToastFrame {
+                index,
+                animation,
+                rect,
+                scaling,
+                opacity,
+                drag,
+                mut shapes,
+                ..
+            } = frame;
+            let toast = &mut toasts[index];
+
+            if cross_clicked {
+                toast.dismiss_with(DismissReason::Closed);
+            }
+            if let Some(action) = action.and_then(|index| toast.actions.get(index)) {
+                events.push(ToastEvent {
+                    id: toast.id,
+                    kind: ToastEventKind::Action(action.key.clone()),
+                });
+                toast.dismiss_with(DismissReason::Action);
+            }
+
+            // Clicks on the cross or buttons are not reported for the toast itself
+            if response.clicked() {
+                events.push(ToastEvent {
+                    id: toast.id,
+                    kind: ToastEventKind::Clicked,
+                });
+                toast.clicked();
+            }
+
+            // Toasts are swiped towards their side, centered ones either way
+            let side = anchor.anim_side();
+            let dir = if side == 0. { drag.signum() } else { side };
+            if response.drag_stopped() {
+                let velocity = ctx.input(|i| i.pointer.velocity().x);
+                if drag * dir > toast.width * SWIPE_DISTANCE || velocity * dir > SWIPE_VELOCITY {
+                    toast.dismiss_with(DismissReason::Swiped);
+                } else {
+                    toast.drag = None;
+                }
+            } else if response.dragged() {
+                let drag = drag + response.drag_delta().x;
+                toast.drag = Some(if side == 0. {
+                    drag
+                } else {
+                    side * (drag * side).max(0.)
+                });
+                update = true;
+            }
+
+            // Time passed since the toast was last shown, independent of the frame rate
+            let elapsed = toast
+                .last_tick
+                .map_or(0., |last| (now - last).max(0.) as f32);
+            toast.last_tick = Some(now);
+
+            if let Some((_, d)) = toast.duration.as_mut() {
+                // Check if we hover over the toast and if true don't decrease the duration
+                if !response.contains_pointer() && toast.state.idling() {
+                    *d -= elapsed;
+
+                    // Progress bar has to be redrawn every frame, otherwise wake up at expiry
+                    if toast.show_progress_bar {
+                        update = true;
+                    } else {
+                        next_expiry = Some(next_expiry.map_or(*d, |e: f32| e.min(*d)));
                     }
                 }
             }
@@ -396,23 +968,32 @@ impl Toasts {
             if toast.show_progress_bar {
                 if let Some((initial, current)) = toast.duration {
                     if !toast.state.disappearing() {
-                        p.line_segment(
+                        shapes.push(Shape::line_segment(
                             [
                                 rect.min + vec2(0., toast.height),
                                 rect.max - vec2((1. - (current / initial)) * toast.width, 0.),
                             ],
                             Stroke::new(4., visuals.fg_stroke.color),
-                        );
+                        ));
                     }
                 }
             }
 
-            toast.adjust_next_pos(&mut pos, *anchor, *spacing);
+            let mut shape = Shape::Vec(shapes);
+            shape.transform(scaling);
+            let mut toast_painter = stack_painter.clone();
+            toast_painter.set_opacity(opacity);
+            toast_painter.set(shape_idx, shape);
 
             // Animations
+            let step = if animation == ToastAnimation::None || *speed <= 0. {
+                1.
+            } else {
+                ctx.input(|i| i.stable_dt) * (*speed)
+            };
             if toast.state.appearing() {
                 update = true;
-                toast.value += ctx.input(|i| i.stable_dt) * (*speed);
+                toast.value += step;
 
                 if toast.value >= 1. {
                     toast.value = 1.;
@@ -420,19 +1001,34 @@ impl Toasts {
                 }
             } else if toast.state.disappearing() {
                 update = true;
-                toast.value -= ctx.input(|i| i.stable_dt) * (*speed);
+                toast.value -= step;
 
                 if toast.value <= 0. {
                     toast.state = ToastState::Disappeared;
                 }
             }
+        }
 
-            // Remove disappeared toasts
-            !toast.state.disappeared()
+        // Remove disappeared toasts
+        toasts.retain_mut(|toast| {
+            if !toast.state.disappeared() {
+                return true;
+            }
+            events.push(ToastEvent {
+                id: toast.id,
+                kind: ToastEventKind::Dismissed(toast.removed()),
+            });
+            false
         });
 
         if update {
             ctx.request_repaint();
+        } else if let Some(secs) = next_expiry {
+            ctx.request_repaint_after_secs(secs.max(0.));
+        }
+
+        if events.len() > MAX_EVENTS {
+            events.drain(..events.len() - MAX_EVENTS);
         }
     }
 }
@@ -443,6 +1039,89 @@ impl Default for Toasts {
     }
 }
 
-fn ease_in_cubic(x: f32) -> f32 {
-    1. - (1. - x).powi(3)
+/// Toast laid out for the current frame, it is painted and interacted with once the whole
+/// stack is laid out.
+struct ToastFrame {
+    index: usize,
+    id: ToastId,
+    z: usize,
+    interactive: bool,
+    animation: ToastAnimation,
+    rect: Rect,
+    scaling: TSTransform,
+    opacity: f32,
+    drag: f32,
+    shapes: Vec<Shape>,
+    cross: Option<Rect>,
+    buttons: Vec<(Rect, Arc<Galley>)>,
+}
+
+fn screen_layer() -> LayerId {
+    LayerId::new(Order::Foreground, Id::new("toasts"))
+}
+
+fn screen_painter(ctx: &Context) -> Painter {
+    ctx.layer_painter(screen_layer())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::RawInput;
+    use std::time::Duration;
+
+    fn run(ctx: &Context, toasts: &mut Toasts, time: f64) {
+        let input = RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    }
+
+    #[test]
+    fn overflow_start() {
+        let mut t = Toasts::default();
+        let ids: Vec<_> = (0..4).map(|_| t.basic("").id()).collect();
+        assert_eq!(t.overflow_start(ViewportId::ROOT), None);
+
+        let mut t = Toasts {
+            max_visible: Some(2),
+            ..t
+        };
+        assert_eq!(t.overflow_start(ViewportId::ROOT), Some(ids[2]));
+
+        // Toasts of other viewports are not counted
+        t.toasts[0].viewport = Some(ViewportId::from_hash_of("other"));
+        assert_eq!(t.overflow_start(ViewportId::ROOT), Some(ids[3]));
+
+        t.expanded = true;
+        assert_eq!(t.overflow_start(ViewportId::ROOT), None);
+    }
+
+    #[test]
+    fn queued_toasts_dont_tick() {
+        let ctx = Context::default();
+        let mut t = Toasts::default().with_max_visible(1);
+        let shown = t.basic("").duration(Duration::from_secs(1)).id();
+        let queued = t.basic("").duration(Duration::from_secs(1)).id();
+
+        let mut time = 0.;
+        while t.contains(shown) && time < 10. {
+            run(&ctx, &mut t, time);
+            assert_eq!(
+                t.get(queued).and_then(|toast| toast.duration),
+                Some((1., 1.))
+            );
+            time += 0.1;
+        }
+        assert!(!t.contains(shown));
+
+        // The queued toast starts ticking once shown, without the time it spent queued
+        run(&ctx, &mut t, time);
+        assert_eq!(
+            t.get(queued).and_then(|toast| toast.duration),
+            Some((1., 1.))
+        );
+        assert_eq!(t.get(queued).and_then(|toast| toast.last_tick), Some(time));
+    }
 }
@@ -0,0 +1,158 @@
+//! [`log`](::log) backend that shows log records as toasts.
+
+use crate::{Toast, ToastLevel, ToastSender};
+use ::log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+/// Logger that turns log records into toasts and passes them on to an inner logger.
+/// egui logs while its context is locked, so the repaint for new toasts is requested from
+/// a background thread instead of the logging one.
+/// # Usage
+/// ```
+/// use egui_notify::{log::ToastLogger, Toasts};
+/// use log::LevelFilter;
+///
+/// let mut toasts = Toasts::default();
+/// ToastLogger::new(toasts.sender())
+///     .with_level(LevelFilter::Info)
+///     .with_target("noisy_crate", LevelFilter::Off)
+///     .init()
+///     .unwrap();
+///
+/// log::warn!("Connection lost, retrying");
+/// ```
+pub struct ToastLogger {
+    sender: ToastSender,
+    level: LevelFilter,
+    debug_level: ToastLevel,
+    targets: Vec<(String, LevelFilter)>,
+    inner: Option<Box<dyn Log>>,
+}
+
+impl ToastLogger {
+    /// Creates new [`ToastLogger`] that shows warnings and errors.
+    pub fn new(sender: ToastSender) -> Self {
+        Self {
+            sender,
+            level: LevelFilter::Warn,
+            debug_level: ToastLevel::None,
+            targets: vec![],
+            inner: None,
+        }
+    }
+
+    /// Minimum level of records that are shown as toasts.
+    pub const fn with_level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Level of the toasts created for `Debug` and `Trace` records.
+    pub fn with_debug_level(mut self, level: ToastLevel) -> Self {
+        self.debug_level = level;
+        self
+    }
+
+    /// Overrides the minimum level for records whose target starts with `target`.
+    /// The longest matching target wins.
+    pub fn with_target(mut self, target: impl Into<String>, level: LevelFilter) -> Self {
+        self.targets.push((target.into(), level));
+        self
+    }
+
+    /// Logger that receives every record after it was turned into a toast.
+    pub fn with_inner(mut self, inner: impl Log + 'static) -> Self {
+        self.inner = Some(Box::new(inner));
+        self
+    }
+
+    /// Installs this logger as the global logger.
+    pub fn init(self) -> Result<(), SetLoggerError> {
+        let max_level = if self.inner.is_some() {
+            LevelFilter::Trace
+        } else {
+            self.targets
+                .iter()
+                .map(|(_, level)| *level)
+                .fold(self.level, Ord::max)
+        };
+
+        ::log::set_boxed_logger(Box::new(self))?;
+        ::log::set_max_level(max_level);
+        Ok(())
+    }
+
+    fn toast_enabled(&self, metadata: &Metadata) -> bool {
+        let level = self
+            .targets
+            .iter()
+            .filter(|(target, _)| metadata.target().starts_with(target.as_str()))
+            .max_by_key(|(target, _)| target.len())
+            .map_or(self.level, |(_, level)| *level);
+
+        metadata.level() <= level
+    }
+
+    fn toast_level(&self, level: Level) -> ToastLevel {
+        match level {
+            Level::Error => ToastLevel::Error,
+            Level::Warn => ToastLevel::Warning,
+            Level::Info => ToastLevel::Info,
+            Level::Debug | Level::Trace => self.debug_level.clone(),
+        }
+    }
+}
+
+impl Log for ToastLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.toast_enabled(metadata)
+            || self
+                .inner
+                .as_ref()
+                .is_some_and(|inner| inner.enabled(metadata))
+    }
+
+    fn log(&self, record: &Record) {
+        if self.toast_enabled(record.metadata()) {
+            self.sender.add_deferred(Toast::custom(
+                record.args().to_string(),
+                self.toast_level(record.level()),
+            ));
+        }
+
+        if let Some(inner) = &self.inner {
+            inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(inner) = &self.inner {
+            inner.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled(logger: &ToastLogger, target: &str, level: Level) -> bool {
+        logger.toast_enabled(&Metadata::builder().target(target).level(level).build())
+    }
+
+    #[test]
+    fn target_filters() {
+        let logger = ToastLogger::new(ToastSender::default())
+            .with_level(LevelFilter::Warn)
+            .with_target("net", LevelFilter::Off)
+            .with_target("net::http", LevelFilter::Debug);
+
+        assert!(enabled(&logger, "app", Level::Warn));
+        assert!(!enabled(&logger, "app", Level::Info));
+        assert!(!enabled(&logger, "net", Level::Error));
+        assert!(!enabled(&logger, "net::tcp", Level::Error));
+        // The longest matching target wins, regardless of the order they were added in
+        assert!(enabled(&logger, "net::http", Level::Debug));
+        assert!(enabled(&logger, "net::http::client", Level::Info));
+        assert!(!enabled(&logger, "net::http", Level::Trace));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::Toasts;
+use egui::WidgetText;
+use std::fmt::Display;
+
+/// Reports the outcome of a [`Result`] as a toast and passes the result on.
+/// # Usage
+/// ```
+/// use egui_notify::{ToastResultExt, Toasts};
+///
+/// let mut t = Toasts::default();
+/// let port = "80a"
+///     .parse::<u16>()
+///     .toast_err_with(&mut t, |e| format!("Invalid port: {e}"))
+///     .unwrap_or(8080);
+/// # assert_eq!(port, 8080);
+/// # assert_eq!(t.len(), 1);
+/// ```
+pub trait ToastResultExt<T, E>: Sized {
+    /// Shows an error toast with the error's [`Display`] output if `self` is `Err`.
+    fn toast_err(self, toasts: &mut Toasts) -> Self
+    where
+        E: Display;
+
+    /// Shows an error toast with the caption built from the error if `self` is `Err`.
+    fn toast_err_with<C: Into<WidgetText>>(
+        self,
+        toasts: &mut Toasts,
+        caption: impl FnOnce(&E) -> C,
+    ) -> Self;
+
+    /// Shows a success toast with `caption` if `self` is `Ok`.
+    fn toast_ok(self, toasts: &mut Toasts, caption: impl Into<WidgetText>) -> Self;
+}
+
+impl<T, E> ToastResultExt<T, E> for Result<T, E> {
+    fn toast_err(self, toasts: &mut Toasts) -> Self
+    where
+        E: Display,
+    {
+        self.toast_err_with(toasts, ToString::to_string)
+    }
+
+    fn toast_err_with<C: Into<WidgetText>>(
+        self,
+        toasts: &mut Toasts,
+        caption: impl FnOnce(&E) -> C,
+    ) -> Self {
+        if let Err(e) = &self {
+            toasts.error(caption(e));
+        }
+        self
+    }
+
+    fn toast_ok(self, toasts: &mut Toasts, caption: impl Into<WidgetText>) -> Self {
+        if self.is_ok() {
+            toasts.success(caption);
+        }
+        self
+    }
+}
+
+/// Reports the outcome of an [`Option`] as a toast and passes the option on.
+pub trait ToastOptionExt<T>: Sized {
+    /// Shows an error toast with `caption` if `self` is `None`.
+    fn toast_none(self, toasts: &mut Toasts, caption: impl Into<WidgetText>) -> Self;
+
+    /// Shows a success toast with `caption` if `self` is `Some`.
+    fn toast_some(self, toasts: &mut Toasts, caption: impl Into<WidgetText>) -> Self;
+}
+
+impl<T> ToastOptionExt<T> for Option<T> {
+    fn toast_none(self, toasts: &mut Toasts, caption: impl Into<WidgetText>) -> Self {
+        if self.is_none() {
+            toasts.error(caption);
+        }
+        self
+    }
+
+    fn toast_some(self, toasts: &mut Toasts, caption: impl Into<WidgetText>) -> Self {
+        if self.is_some() {
+            toasts.success(caption);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToastLevel;
+
+    fn shown(toasts: &Toasts) -> Vec<(ToastLevel, String)> {
+        toasts
+            .toasts
+            .iter()
+            .map(|toast| (toast.level.clone(), toast.caption.text().to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn result_is_passed_on() {
+        let mut t = Toasts::default();
+        assert_eq!(Ok::<u8, String>(1).toast_err(&mut t), Ok(1));
+        assert_eq!(
+            Err::<u8, _>("Disk full".to_owned()).toast_err(&mut t),
+            Err("Disk full".to_owned())
+        );
+        assert_eq!(Ok::<u8, u8>(2).toast_ok(&mut t, "Saved"), Ok(2));
+        assert_eq!(Err::<u8, u8>(3).toast_ok(&mut t, "Saved"), Err(3));
+        assert_eq!(
+            Err::<u8, u8>(4).toast_err_with(&mut t, |e| format!("Error {e}")),
+            Err(4)
+        );
+        assert_eq!(Ok::<u8, u8>(5).toast_err_with(&mut t, |_| "Unused"), Ok(5));
+
+        assert_eq!(
+            shown(&t),
+            [
+                (ToastLevel::Error, "Disk full".to_owned()),
+                (ToastLevel::Success, "Saved".to_owned()),
+                (ToastLevel::Error, "Error 4".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn option_is_passed_on() {
+        let mut t = Toasts::default();
+        assert_eq!(Some(1).toast_none(&mut t, "Missing"), Some(1));
+        assert_eq!(None::<u8>.toast_none(&mut t, "Missing"), None);
+        assert_eq!(Some(2).toast_some(&mut t, "Found"), Some(2));
+        assert_eq!(None::<u8>.toast_some(&mut t, "Found"), None);
+
+        assert_eq!(
+            shown(&t),
+            [
+                (ToastLevel::Error, "Missing".to_owned()),
+                (ToastLevel::Success, "Found".to_owned()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{Toast, ToastId, ToastLevel};
+use egui::{Color32, Context, WidgetText};
+#[cfg(any(feature = "log", feature = "tracing"))]
+use std::sync::{mpsc, OnceLock};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+#[derive(Default)]
+struct ToastQueue {
+    toasts: Vec<Toast>,
+    // Context of the last `Toasts::show` call, used to wake up the UI
+    ctx: Option<Context>,
+}
+
+/// Cloneable handle for adding toasts from other threads.
+/// Toasts are queued and moved into [`crate::Toasts`] on the next `show` call.
+/// # Usage
+/// ```
+/// use egui_notify::Toasts;
+///
+/// let mut t = Toasts::default();
+/// let sender = t.sender();
+/// std::thread::spawn(move || {
+///     sender.info("Download finished");
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+#[derive(Clone, Default)]
+pub struct ToastSender {
+    queue: Arc<Mutex<ToastQueue>>,
+    // Wakes the UI from a background thread, see `add_deferred`
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    waker: Arc<OnceLock<mpsc::Sender<()>>>,
+}
+
+fn lock(queue: &Mutex<ToastQueue>) -> MutexGuard<'_, ToastQueue> {
+    queue.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn wake(queue: &Mutex<ToastQueue>) {
+    // Waiting for the context while the queue is locked can deadlock against the UI thread
+    let ctx = lock(queue).ctx.clone();
+    if let Some(ctx) = ctx {
+        ctx.request_repaint();
+    }
+}
+
+impl ToastSender {
+    fn lock(&self) -> MutexGuard<'_, ToastQueue> {
+        lock(&self.queue)
+    }
+
+    /// Queues new toast and requests a repaint so it shows up promptly.
+    pub fn add(&self, toast: Toast) -> ToastId {
+        let id = toast.id;
+        self.lock().toasts.push(toast);
+        wake(&self.queue);
+        id
+    }
+
+    /// Queues new toast and requests the repaint from a background thread.
+    /// Loggers use this because egui logs while it holds its context lock, waking the
+    /// context from the logging thread would then deadlock.
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    pub(crate) fn add_deferred(&self, toast: Toast) -> ToastId {
+        let id = toast.id;
+        self.lock().toasts.push(toast);
+        let waker = self.waker.get_or_init(|| {
+            let (tx, rx) = mpsc::channel();
+            // The thread exits once every sender is dropped
+            let queue = Arc::downgrade(&self.queue);
+            let _ = std::thread::Builder::new()
+                .name("egui_notify_waker".into())
+                .spawn(move || {
+                    while rx.recv().is_ok() {
+                        match queue.upgrade() {
+                            Some(queue) => wake(&queue),
+                            None => break,
+                        }
+                    }
+                });
+            tx
+        });
+        // Without the thread the toast still shows up on the next repaint
+        let _ = waker.send(());
+        id
+    }
+
+    /// Shortcut for adding a toast with success `level`.
+    pub fn success(&self, caption: impl Into<WidgetText>) -> ToastId {
+        self.add(Toast::success(caption))
+    }
+
+    /// Shortcut for adding a toast with info `level`.
+    pub fn info(&self, caption: impl Into<WidgetText>) -> ToastId {
+        self.add(Toast::info(caption))
+    }
+
+    /// Shortcut for adding a toast with warning `level`.
+    pub fn warning(&self, caption: impl Into<WidgetText>) -> ToastId {
+        self.add(Toast::warning(caption))
+    }
+
+    /// Shortcut for adding a toast with error `level`.
+    pub fn error(&self, caption: impl Into<WidgetText>) -> ToastId {
+        self.add(Toast::error(caption))
+    }
+
+    /// Shortcut for adding a toast with no level.
+    pub fn basic(&self, caption: impl Into<WidgetText>) -> ToastId {
+        self.add(Toast::basic(caption))
+    }
+
+    /// Shortcut for adding a toast with custom `level`.
+    pub fn custom(
+        &self,
+        caption: impl Into<WidgetText>,
+        level_string: String,
+        level_color: Color32,
+    ) -> ToastId {
+        self.add(Toast::custom(
+            caption,
+            ToastLevel::Custom(level_string, level_color),
+        ))
+    }
+
+    /// Returns `true` if the toast with the given `id` is still queued.
+    pub(crate) fn is_pending(&self, id: ToastId) -> bool {
+        self.lock().toasts.iter().any(|toast| toast.id == id)
+    }
+
+    /// Takes all queued toasts.
+    pub(crate) fn take(&self) -> Vec<Toast> {
+        std::mem::take(&mut self.lock().toasts)
+    }
+
+    /// Takes all queued toasts and remembers `ctx` for later repaint requests.
+    pub(crate) fn drain(&self, ctx: &Context) -> Vec<Toast> {
+        let mut queue = self.lock();
+        if queue.ctx.is_none() {
+            queue.ctx = Some(ctx.clone());
+        }
+        std::mem::take(&mut queue.toasts)
+    }
+}
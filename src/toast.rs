@@ -1,6 +1,24 @@
-use crate::{Anchor, TOAST_HEIGHT, TOAST_WIDTH};
-use egui::{pos2, vec2, Color32, Pos2, Rect, WidgetText};
-use std::{fmt::Debug, time::Duration};
+use crate::{
+    animation::Tween, galleys::ToastGalleys, Anchor, DismissReason, ToastAnimation, TOAST_HEIGHT,
+    TOAST_WIDTH,
+};
+use egui::{vec2, Color32, Pos2, Rect, Vec2, ViewportId, WidgetText};
+use std::{
+    fmt::Debug,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+/// Stable handle of a [`Toast`], can be used to access the toast on later frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ToastId(u64);
+
+impl ToastId {
+    fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
 
 /// Level of importance
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -50,6 +68,22 @@ impl ToastState {
     }
 }
 
+/// How the remaining time of a toast changes when its duration is updated
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DurationPolicy {
+    /// Restart the countdown with the new duration.
+    #[default]
+    Reset,
+    /// Add the new duration on top of the time that is left.
+    Extend,
+}
+
+/// Button shown next to the caption of a toast
+pub(crate) struct ToastAction {
+    pub(crate) label: WidgetText,
+    pub(crate) key: String,
+}
+
 /// Container for options for initlizing toasts
 pub struct ToastOptions {
     duration: Option<Duration>,
@@ -60,6 +94,7 @@ pub struct ToastOptions {
 
 /// Single notification or *toast*
 pub struct Toast {
+    pub(crate) id: ToastId,
     pub(crate) level: ToastLevel,
     pub(crate) caption: WidgetText,
     // (initial, current)
@@ -68,9 +103,30 @@ pub struct Toast {
     pub(crate) width: f32,
     pub(crate) closable: bool,
     pub(crate) show_progress_bar: bool,
+    pub(crate) viewport: Option<ViewportId>,
+    pub(crate) max_width: Option<f32>,
+    pub(crate) animation: Option<ToastAnimation>,
+    pub(crate) actions: Vec<ToastAction>,
 
     pub(crate) state: ToastState,
     pub(crate) value: f32,
+    // Time of the last frame the toast was shown at, in seconds
+    pub(crate) last_tick: Option<f64>,
+    // Vertical offset from the stack position, `None` until the toast is shown
+    pub(crate) offset: Option<Tween<f32>>,
+    // Animated size, `None` until the toast is shown
+    pub(crate) size: Option<Tween<Vec2>>,
+    // Horizontal offset the toast was dragged to, `None` when it is not dragged
+    pub(crate) drag: Option<f32>,
+    // Offset the toast springs back from once released
+    pub(crate) drag_spring: Tween<f32>,
+    pub(crate) galleys: Option<ToastGalleys>,
+    pub(crate) shown: bool,
+    pub(crate) dismiss_reason: Option<DismissReason>,
+
+    on_click: Option<Box<dyn FnMut() + Send + Sync>>,
+    on_dismiss: Option<Box<dyn FnOnce(DismissReason) + Send + Sync>>,
+    on_expire: Option<Box<dyn FnOnce() + Send + Sync>>,
 }
 
 impl Default for ToastOptions {
@@ -91,6 +147,7 @@ fn duration_to_seconds_f32(duration: Duration) -> f32 {
 impl Toast {
     fn new(caption: impl Into<WidgetText>, options: ToastOptions) -> Self {
         Self {
+            id: ToastId::next(),
             caption: caption.into(),
             height: TOAST_HEIGHT,
             width: TOAST_WIDTH,
@@ -100,9 +157,24 @@ impl Toast {
             }),
             closable: options.closable,
             show_progress_bar: options.show_progress_bar,
+            viewport: None,
+            max_width: None,
+            animation: None,
+            actions: vec![],
             level: options.level,
             value: 0.,
             state: ToastState::Appear,
+            last_tick: None,
+            offset: None,
+            size: None,
+            drag: None,
+            drag_spring: Tween::new(0.),
+            galleys: None,
+            shown: false,
+            dismiss_reason: None,
+            on_click: None,
+            on_dismiss: None,
+            on_expire: None,
         }
     }
 
@@ -167,6 +239,11 @@ impl Toast {
         )
     }
 
+    /// Returns the handle of this toast.
+    pub const fn id(&self) -> ToastId {
+        self.id
+    }
+
     /// Set the options with a [`ToastOptions`]
     pub fn options(&mut self, options: ToastOptions) -> &mut Self {
         self.closable(options.closable);
@@ -175,15 +252,24 @@ impl Toast {
         self
     }
 
+    /// Change the caption of the toast
+    pub fn caption(&mut self, caption: impl Into<WidgetText>) -> &mut Self {
+        self.caption = caption.into();
+        self.galleys = None;
+        self
+    }
+
     /// Change the level of the toast
     pub fn level(&mut self, level: ToastLevel) -> &mut Self {
         self.level = level;
+        self.galleys = None;
         self
     }
 
     /// Can the user close the toast?
     pub fn closable(&mut self, closable: bool) -> &mut Self {
         self.closable = closable;
+        self.galleys = None;
         self
     }
 
@@ -193,6 +279,14 @@ impl Toast {
         self
     }
 
+    /// In which viewport should the toast be shown? Toasts without a viewport are shown in
+    /// [`ViewportId::ROOT`]. Toasts expire by wall-clock time, so time that passed while their
+    /// viewport was not shown counts as well.
+    pub fn viewport(&mut self, viewport: impl Into<Option<ViewportId>>) -> &mut Self {
+        self.viewport = viewport.into();
+        self
+    }
+
     /// In what time should the toast expire? Set to `None` for no expiry.
     pub fn duration(&mut self, duration: impl Into<Option<Duration>>) -> &mut Self {
         if let Some(duration) = duration.into() {
@@ -204,6 +298,42 @@ impl Toast {
         self
     }
 
+    /// Updates the duration of a toast that may already be shown, `policy` decides what happens to
+    /// the time that is left. Setting `None` removes the expiry.
+    /// ```
+    /// # use std::time::Duration;
+    /// use egui_notify::{DurationPolicy, ToastLevel, Toasts};
+    ///
+    /// let mut t = Toasts::default();
+    /// let id = t.info("Saving...").duration(None).id();
+    /// // Once saving has finished
+    /// if let Some(toast) = t.get_mut(id) {
+    ///     toast
+    ///         .caption("Saved")
+    ///         .level(ToastLevel::Success)
+    ///         .update_duration(Duration::from_secs(2), DurationPolicy::Reset);
+    /// }
+    /// ```
+    pub fn update_duration(
+        &mut self,
+        duration: impl Into<Option<Duration>>,
+        policy: DurationPolicy,
+    ) -> &mut Self {
+        let Some(duration) = duration.into() else {
+            self.duration = None;
+            return self;
+        };
+
+        let dur = duration_to_seconds_f32(duration);
+        self.duration = match (policy, self.duration) {
+            (DurationPolicy::Extend, Some((initial, current))) => {
+                Some((initial + dur, current.max(0.) + dur))
+            }
+            _ => Some((dur, dur)),
+        };
+        self
+    }
+
     /// Toast's box height
     pub fn height(&mut self, height: f32) -> &mut Self {
         self.height = height;
@@ -216,36 +346,146 @@ impl Toast {
         self
     }
 
+    /// Maximum width of the toast's box, overrides [`crate::Toasts::with_max_width`].
+    /// Longer captions are wrapped.
+    pub fn max_width(&mut self, max_width: impl Into<Option<f32>>) -> &mut Self {
+        self.max_width = max_width.into();
+        self
+    }
+
+    /// How the toast appears and disappears, overrides [`crate::Toasts::with_animation`].
+    pub fn animation(&mut self, animation: impl Into<Option<ToastAnimation>>) -> &mut Self {
+        self.animation = animation.into();
+        self
+    }
+
+    /// Adds an action button next to the caption. Clicking it dismisses the toast and reports
+    /// [`crate::ToastEventKind::Action`] with the given `key`.
+    /// ```
+    /// use egui_notify::{ToastEventKind, Toasts};
+    ///
+    /// let mut t = Toasts::default();
+    /// t.info("File deleted").action("Undo", "undo");
+    /// // After `t.show(ctx)`
+    /// for event in t.drain_events() {
+    ///     if event.kind == ToastEventKind::Action("undo".into()) {
+    ///         // Restore the file
+    ///     }
+    /// }
+    /// ```
+    pub fn action(&mut self, label: impl Into<WidgetText>, key: impl Into<String>) -> &mut Self {
+        self.actions.push(ToastAction {
+            label: label.into(),
+            key: key.into(),
+        });
+        self.galleys = None;
+        self
+    }
+
+    /// Called every time the body of the toast is clicked.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default();
+    /// t.error("Build failed").on_click(|| println!("Opening the build log"));
+    /// ```
+    pub fn on_click(&mut self, on_click: impl FnMut() + Send + Sync + 'static) -> &mut Self {
+        self.on_click = Some(Box::new(on_click));
+        self
+    }
+
+    /// Called once the toast is removed, with the reason it was dismissed for.
+    pub fn on_dismiss(
+        &mut self,
+        on_dismiss: impl FnOnce(DismissReason) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.on_dismiss = Some(Box::new(on_dismiss));
+        self
+    }
+
+    /// Called once the toast is removed after its duration ran out.
+    pub fn on_expire(&mut self, on_expire: impl FnOnce() + Send + Sync + 'static) -> &mut Self {
+        self.on_expire = Some(Box::new(on_expire));
+        self
+    }
+
     /// Dismiss this toast
     pub fn dismiss(&mut self) {
+        self.dismiss_with(DismissReason::Programmatic);
+    }
+
+    /// Dismiss this toast, the first reason given is kept.
+    pub(crate) fn dismiss_with(&mut self, reason: DismissReason) {
         self.state = ToastState::Disappear;
+        self.dismiss_reason.get_or_insert(reason);
     }
 
-    pub(crate) fn calc_anchored_rect(&self, pos: Pos2, anchor: Anchor) -> Rect {
-        match anchor {
-            Anchor::TopRight => Rect {
-                min: pos2(pos.x - self.width, pos.y),
-                max: pos2(pos.x, pos.y + self.height),
-            },
-            Anchor::TopLeft => Rect {
-                min: pos,
-                max: pos + vec2(self.width, self.height),
-            },
-            Anchor::BottomRight => Rect {
-                min: pos - vec2(self.width, self.height),
-                max: pos,
-            },
-            Anchor::BottomLeft => Rect {
-                min: pos2(pos.x, pos.y - self.height),
-                max: pos2(pos.x + self.width, pos.y),
-            },
+    /// Runs the click callback.
+    pub(crate) fn clicked(&mut self) {
+        if let Some(on_click) = &mut self.on_click {
+            on_click();
         }
     }
 
-    pub(crate) fn adjust_next_pos(&self, pos: &mut Pos2, anchor: Anchor, spacing: f32) {
-        match anchor {
-            Anchor::TopRight | Anchor::TopLeft => pos.y += self.height + spacing,
-            Anchor::BottomRight | Anchor::BottomLeft => pos.y -= self.height + spacing,
+    /// Runs the dismiss and expire callbacks, returns why the toast was dismissed.
+    pub(crate) fn removed(&mut self) -> DismissReason {
+        let reason = self.dismiss_reason.unwrap_or(DismissReason::Programmatic);
+        if reason == DismissReason::Expired {
+            if let Some(on_expire) = self.on_expire.take() {
+                on_expire();
+            }
+        }
+        if let Some(on_dismiss) = self.on_dismiss.take() {
+            on_dismiss(reason);
         }
+        reason
+    }
+
+    pub(crate) fn viewport_id(&self) -> ViewportId {
+        self.viewport.unwrap_or(ViewportId::ROOT)
+    }
+
+    pub(crate) fn calc_anchored_rect(&self, pos: Pos2, anchor: Anchor) -> Rect {
+        anchor
+            .toast_align()
+            .anchor_size(pos, vec2(self.width, self.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toast(duration: Option<(f32, f32)>) -> Toast {
+        let mut toast = Toast::basic("");
+        toast.duration = duration;
+        toast
+    }
+
+    #[test]
+    fn update_duration_reset() {
+        let mut t = toast(Some((4., 1.)));
+        t.update_duration(Duration::from_secs(2), DurationPolicy::Reset);
+        assert_eq!(t.duration, Some((2., 2.)));
+
+        t.update_duration(None, DurationPolicy::Reset);
+        assert_eq!(t.duration, None);
+    }
+
+    #[test]
+    fn update_duration_extend() {
+        let mut t = toast(Some((4., 1.)));
+        t.update_duration(Duration::from_secs(2), DurationPolicy::Extend);
+        assert_eq!(t.duration, Some((6., 3.)));
+
+        // Time that ran out is not added
+        let mut t = toast(Some((4., -0.5)));
+        t.update_duration(Duration::from_secs(2), DurationPolicy::Extend);
+        assert_eq!(t.duration, Some((6., 2.)));
+
+        // Toasts without a duration start counting down
+        let mut t = toast(None);
+        t.update_duration(Duration::from_secs(2), DurationPolicy::Extend);
+        assert_eq!(t.duration, Some((2., 2.)));
     }
 }
@@ -0,0 +1,183 @@
+//! [`tracing`](::tracing) layer that shows events as toasts.
+
+use crate::{Toast, ToastLevel, ToastSender};
+use ::tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Level, Subscriber,
+};
+use std::fmt::{Debug, Write};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// Layer that turns [`tracing`](::tracing) events into toasts.
+///
+/// By default only events with `toast = true` field are shown,
+/// use [`ToastLayer::with_level`] to show every event above a level.
+/// egui logs while its context is locked, so the repaint for new toasts is requested from
+/// a background thread instead of the logging one.
+/// # Usage
+/// ```
+/// use egui_notify::{tracing::ToastLayer, Toasts};
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let mut toasts = Toasts::default();
+/// let subscriber = tracing_subscriber::registry().with(ToastLayer::new(toasts.sender()));
+/// tracing::subscriber::with_default(subscriber, || {
+///     tracing::warn!(toast = true, "Connection lost, retrying");
+/// });
+/// ```
+pub struct ToastLayer {
+    sender: ToastSender,
+    level: Option<Level>,
+    marker: &'static str,
+    span_fields: bool,
+}
+
+impl ToastLayer {
+    /// Creates new [`ToastLayer`] that shows events marked with `toast = true`.
+    pub const fn new(sender: ToastSender) -> Self {
+        Self {
+            sender,
+            level: None,
+            marker: "toast",
+            span_fields: false,
+        }
+    }
+
+    /// Also show every event that is at least as severe as `level`.
+    pub const fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Name of the boolean field that marks events to be shown, `toast` by default.
+    pub const fn with_marker(mut self, marker: &'static str) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Should fields of the enclosing spans be shown below the message?
+    pub const fn with_span_fields(mut self, span_fields: bool) -> Self {
+        self.span_fields = span_fields;
+        self
+    }
+}
+
+// Formatted fields of a span, stored in its extensions
+struct SpanFields(String);
+
+impl<S> Layer<S> for ToastLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if !self.span_fields {
+            return;
+        }
+
+        if let Some(span) = ctx.span(id) {
+            let mut fields = String::new();
+            attrs.record(&mut FieldsVisitor(&mut fields));
+            span.extensions_mut().insert(SpanFields(fields));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(SpanFields(fields)) = span.extensions_mut().get_mut::<SpanFields>() {
+                values.record(&mut FieldsVisitor(fields));
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut visitor = EventVisitor {
+            marker: self.marker,
+            marked: false,
+            message: None,
+        };
+        event.record(&mut visitor);
+
+        let level = *event.metadata().level();
+        let meets_level = self.level.is_some_and(|min| level <= min);
+        if !(visitor.marked || meets_level) {
+            return;
+        }
+
+        let mut caption = visitor.message.unwrap_or_default();
+        if self.span_fields {
+            let details = ctx
+                .event_scope(event)
+                .into_iter()
+                .flat_map(|scope| scope.from_root())
+                .filter_map(|span| {
+                    let extensions = span.extensions();
+                    let SpanFields(fields) = extensions.get::<SpanFields>()?;
+                    (!fields.is_empty()).then(|| fields.clone())
+                })
+                .collect::<Vec<_>>();
+
+            if !details.is_empty() {
+                caption.push('\n');
+                caption.push_str(&details.join(", "));
+            }
+        }
+
+        let toast_level = match level {
+            Level::ERROR => ToastLevel::Error,
+            Level::WARN => ToastLevel::Warning,
+            Level::INFO => ToastLevel::Info,
+            _ => ToastLevel::None,
+        };
+        self.sender
+            .add_deferred(Toast::custom(caption, toast_level));
+    }
+}
+
+struct EventVisitor {
+    marker: &'static str,
+    marked: bool,
+    message: Option<String>,
+}
+
+impl Visit for EventVisitor {
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        if field.name() == self.marker {
+            self.marked = value;
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_owned());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{value:?}"));
+        }
+    }
+}
+
+struct FieldsVisitor<'a>(&'a mut String);
+
+impl FieldsVisitor<'_> {
+    fn separate(&mut self) {
+        if !self.0.is_empty() {
+            self.0.push_str(", ");
+        }
+    }
+}
+
+impl Visit for FieldsVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.separate();
+        let _ = write!(self.0, "{}={value}", field.name());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.separate();
+        let _ = write!(self.0, "{}={value:?}", field.name());
+    }
+}